@@ -0,0 +1,45 @@
+#[cfg(all(feature = "builder", feature = "plotters"))]
+fn main() {
+    use plot_commands::draw_command::{
+        chart::annotation::{HAlign, VAlign},
+        plot_chart,
+    };
+
+    // validation loss of a training run
+    let loss = (0..60)
+        .map(|epoch| {
+            let e = epoch as f32;
+            (
+                e,
+                2.0 * (-e / 12.0).exp() + 0.3 + 0.0004 * (e - 35.0).powi(2),
+            )
+        })
+        .collect::<Vec<_>>();
+    let best = loss
+        .iter()
+        .copied()
+        .min_by(|a, b| a.1.total_cmp(&b.1))
+        .unwrap();
+
+    plot_chart(|b| {
+        b.add_series_l_with(|b| b.data(loss).name("validation loss"))
+            .annotate_with(|a| {
+                a.arrow("best checkpoint", (best.0 - 5.0, 1.4), best)
+                    .color((0.8, 0.1, 0.1, 1.0))
+            })
+            .annotate_with(|a| a.marker("deploy", (10.0, 1.04)).font_size(14.0))
+            .annotate_with(|a| {
+                a.text("lr decay", (45.0, 1.2))
+                    .align(HAlign::Right, VAlign::Top)
+                    .color((0.3, 0.3, 0.3, 1.0))
+            })
+            .title("Training")
+            .y_label_l("loss")
+    })
+    .plot_png("./annotations.png", (640, 480));
+}
+
+#[cfg(not(all(feature = "builder", feature = "plotters")))]
+fn main() {
+    panic!("This example requires the `builder` and `plotters` features");
+}
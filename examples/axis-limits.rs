@@ -0,0 +1,52 @@
+#[cfg(all(feature = "builder", feature = "plotters"))]
+fn main() {
+    use plot_commands::draw_command::{plot_chart, plot_layout};
+
+    // two runs compared side by side on the same fixed scale
+    let run = |rate: f32| {
+        (0..50)
+            .map(|i| {
+                let t = i as f32;
+                (t, 100.0 * (1.0 - (-t * rate).exp()))
+            })
+            .collect::<Vec<_>>()
+    };
+
+    // water temperature against depth, with depth increasing downwards
+    let profile = (0..=40)
+        .map(|i| {
+            let depth = i as f32 * 5.0;
+            (4.0 + 16.0 / (1.0 + ((depth - 60.0) / 10.0).exp()), depth)
+        })
+        .collect::<Vec<_>>();
+
+    plot_layout(|b| {
+        b.hsplit([
+            plot_chart(|b| {
+                b.add_series_l_with(|b| b.data(run(0.05)).name("run a"))
+                    .y_axis_l_with(|a| a.limits(0.0, 100.0))
+                    .x_axis_with(|a| a.padding(0.05))
+                    .title("Run A")
+            }),
+            plot_chart(|b| {
+                b.add_series_l_with(|b| b.data(run(0.15)).name("run b"))
+                    .y_axis_l_with(|a| a.limits(0.0, 100.0))
+                    .x_axis_with(|a| a.padding(0.05))
+                    .title("Run B")
+            }),
+            plot_chart(|b| {
+                b.add_series_l_with(|b| b.data(profile).scatter().name("temperature"))
+                    .y_axis_l_with(|a| a.min(0.0).inverted())
+                    .x_axis_with(|a| a.padding(0.1))
+                    .title("Depth profile")
+                    .y_label_l("depth (m)")
+            }),
+        ])
+    })
+    .plot_png("./axis-limits.png", (1200, 480));
+}
+
+#[cfg(not(all(feature = "builder", feature = "plotters")))]
+fn main() {
+    panic!("This example requires the `builder` and `plotters` features");
+}
@@ -0,0 +1,37 @@
+#[cfg(all(feature = "builder", feature = "plotters"))]
+fn main() {
+    use plot_commands::{colors::colormap::Colormap, draw_command::plot_chart};
+
+    // deterministic "countries": income, life expectancy, population and region index
+    let countries = (0..40)
+        .map(|i| {
+            let u = ((i * 7919 + 104729) % 1000) as f32 / 1000.0;
+            let v = ((i * 6271 + 7907) % 1000) as f32 / 1000.0;
+            let income = 1.0 + u * 60.0;
+            let life = 55.0 + 25.0 * (income / 60.0).sqrt() + (v - 0.5) * 6.0;
+            let population = 1.0 + v * v * 300.0;
+            (income, life, population, u * 2.0 + v)
+        })
+        .collect::<Vec<_>>();
+
+    plot_chart(|b| {
+        b.add_series_l_with(|b| {
+            b.data(countries.iter().map(|c| (c.0, c.1)).collect::<Vec<_>>())
+                .name("countries")
+                .scatter()
+                .sizes(countries.iter().map(|c| c.2).collect::<Vec<_>>())
+                .values(countries.iter().map(|c| c.3).collect::<Vec<_>>())
+                .colormap(Colormap::Plasma)
+                .colorbar("region")
+        })
+        .title("Income and life expectancy")
+        .x_label("income (k$)")
+        .y_label_l("life expectancy")
+    })
+    .plot_png("./bubble.png", (720, 480));
+}
+
+#[cfg(not(all(feature = "builder", feature = "plotters")))]
+fn main() {
+    panic!("This example requires the `builder` and `plotters` features");
+}
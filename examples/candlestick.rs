@@ -0,0 +1,30 @@
+#[cfg(all(feature = "builder", feature = "plotters"))]
+fn main() {
+    use plot_commands::draw_command::plot_chart;
+
+    // a deterministic random walk of daily prices
+    let mut price = 100.0f32;
+    let candles = (0..40)
+        .map(|day| {
+            let open = price;
+            let close = open + ((day * 37 % 17) as f32 - 8.0) * 0.6;
+            let high = open.max(close) + (day * 13 % 5) as f32 * 0.4;
+            let low = open.min(close) - (day * 7 % 5) as f32 * 0.4;
+            price = close;
+            (day as f32, open, high, low, close)
+        })
+        .collect::<Vec<_>>();
+
+    plot_chart(|b| {
+        b.add_series_l_with(|b| b.candlestick(candles).name("ACME"))
+            .title("Daily prices")
+            .x_label("day")
+            .y_label_l("price")
+    })
+    .plot_png("./candlestick.png", (640, 480));
+}
+
+#[cfg(not(all(feature = "builder", feature = "plotters")))]
+fn main() {
+    panic!("This example requires the `builder` and `plotters` features");
+}
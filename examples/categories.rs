@@ -0,0 +1,51 @@
+#[cfg(all(feature = "builder", feature = "plotters"))]
+fn main() {
+    use std::collections::HashMap;
+
+    use plot_commands::draw_command::{plot_chart, plot_layout};
+
+    // throughput of a few benchmarks, before and after a change
+    let before = vec![
+        ("parse", 120.0),
+        ("render", 45.0),
+        ("layout", 80.0),
+        ("io", 30.0),
+    ];
+    let after = HashMap::from([
+        ("parse".to_string(), 150.0),
+        ("render".to_string(), 52.0),
+        ("layout".to_string(), 79.0),
+        ("compress".to_string(), 64.0),
+    ]);
+
+    // accuracy of models with long names, which are too crowded to be laid out horizontally
+    let models = [
+        ("resnet-50-imagenet", 0.76),
+        ("efficientnet-b4-noisy-student", 0.83),
+        ("vit-base-patch16-224", 0.81),
+        ("convnext-tiny-22k", 0.82),
+        ("mobilenet-v3-large", 0.75),
+        ("deit-small-distilled", 0.80),
+    ];
+
+    plot_layout(|b| {
+        b.hsplit([
+            plot_chart(|b| {
+                b.add_series_l_with(|b| b.categorical_data(before).scatter().name("before"))
+                    .add_series_l_with(|b| b.categorical_data(after).scatter().name("after"))
+                    .title("Benchmarks (ops/s)")
+            }),
+            plot_chart(|b| {
+                b.add_series_l_with(|b| b.categorical_data(models).stem().name("top-1"))
+                    .sort_categories()
+                    .title("Model accuracy")
+            }),
+        ])
+    })
+    .plot_png("./categories.png", (1024, 512));
+}
+
+#[cfg(not(all(feature = "builder", feature = "plotters")))]
+fn main() {
+    panic!("This example requires the `builder` and `plotters` features");
+}
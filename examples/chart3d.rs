@@ -0,0 +1,56 @@
+#[cfg(all(feature = "builder", feature = "plotters"))]
+fn main() {
+    use plot_commands::draw_command::{plot_chart3d, plot_layout};
+
+    // gradient descent on f(x, y) = x^2 + 3 y^2 + sin(2x)
+    let f = |x: f32, y: f32| x * x + 3.0 * y * y + (2.0 * x).sin();
+    let mut p = (1.8f32, 1.2f32);
+    let trajectory = (0..25)
+        .map(|_| {
+            let point = (p.0, p.1, f(p.0, p.1));
+            let grad = (2.0 * p.0 + 2.0 * (2.0 * p.0).cos(), 6.0 * p.1);
+            p = (p.0 - 0.08 * grad.0, p.1 - 0.08 * grad.1);
+            point
+        })
+        .collect::<Vec<_>>();
+
+    plot_layout(|b| {
+        b.hsplit([
+            plot_chart3d(|b| {
+                b.add_series_with(|b| {
+                    b.surface_fn(f, (-2.0, 2.0), (-1.5, 1.5), (40, 30))
+                        .colorbar("loss")
+                })
+                .add_series_with(|b| b.data(trajectory.clone()).name("descent").line())
+                .add_series_with(|b| {
+                    b.data(trajectory.clone())
+                        .scatter()
+                        .color((1.0, 0.0, 0.0, 1.0))
+                })
+                .title("Loss surface")
+                .x_label("x")
+                .y_label("y")
+                .z_label("loss")
+            }),
+            plot_chart3d(|b| {
+                b.add_series_with(|b| {
+                    b.data((0..400).map(|i| {
+                        let t = i as f32 * 0.05;
+                        (t.cos(), t.sin(), t * 0.1)
+                    }))
+                    .name("helix")
+                })
+                .title("Helix")
+                .yaw(1.0)
+                .pitch(0.5)
+                .scale(0.7)
+            }),
+        ])
+    })
+    .plot_png("./chart3d.png", (1280, 520));
+}
+
+#[cfg(not(all(feature = "builder", feature = "plotters")))]
+fn main() {
+    panic!("This example requires the `builder` and `plotters` features");
+}
@@ -0,0 +1,41 @@
+#[cfg(all(feature = "builder", feature = "plotters"))]
+fn main() {
+    use plot_commands::{
+        colors::colormap::Colormap,
+        draw_command::{plot_chart, plot_layout},
+    };
+
+    // Himmelblau's function, a common optimization test surface
+    fn loss(x: f32, y: f32) -> f32 {
+        ((x * x + y - 11.0).powi(2) + (x + y * y - 7.0).powi(2)).ln()
+    }
+
+    plot_layout(|b| {
+        b.hsplit([
+            plot_chart(|b| {
+                b.add_series_l_with(|b| {
+                    b.contour_fn(loss, (-5.0, 5.0), (-5.0, 5.0), (100, 100))
+                        .levels(10)
+                        .level_labels()
+                })
+                .title("Contour")
+            }),
+            plot_chart(|b| {
+                b.add_series_l_with(|b| {
+                    b.contour_fn(loss, (-5.0, 5.0), (-5.0, 5.0), (100, 100))
+                        .levels([1.0, 2.0, 3.0, 4.0, 5.0, 6.0])
+                        .filled()
+                        .colormap(Colormap::Viridis)
+                        .colorbar("log loss")
+                })
+                .title("Filled contour")
+            }),
+        ])
+    })
+    .plot_png("./contour.png", (1024, 480));
+}
+
+#[cfg(not(all(feature = "builder", feature = "plotters")))]
+fn main() {
+    panic!("This example requires the `builder` and `plotters` features");
+}
@@ -0,0 +1,53 @@
+#[cfg(all(feature = "builder", feature = "plotters"))]
+fn main() {
+    use plot_commands::draw_command::{chart::axis::AxisScale, plot_chart, plot_layout};
+
+    // panels of a dashboard for a run that has only just started
+    let first_point = vec![(0.0, 0.82)];
+    let constant = vec![(0.0, 5.0), (1.0, 5.0), (2.0, 5.0), (3.0, 5.0)];
+    let gaps = vec![
+        (0.0, 1.2),
+        (1.0, f32::NAN),
+        (2.0, 2.7),
+        (3.0, f32::INFINITY),
+        (4.0, 3.1),
+    ];
+
+    plot_layout(|b| {
+        b.grid_with_cols(
+            [
+                plot_chart(|b| {
+                    b.add_series_l_with(|b| b.data(first_point).scatter().name("accuracy"))
+                        .title("Single point")
+                }),
+                plot_chart(|b| {
+                    b.add_series_l_with(|b| b.data(constant).name("replicas"))
+                        .title("Constant series")
+                }),
+                plot_chart(|b| b.title("No data yet")),
+                plot_chart(|b| {
+                    b.add_series_l_with(|b| b.data(gaps.clone()).scatter().name("latency"))
+                        .title("Non-finite values")
+                }),
+                plot_chart(|b| {
+                    b.add_series_l_with(|b| b.data(gaps).name("latency"))
+                        .x_axis_with(|a| a.nice())
+                        .y_axis_l_with(|a| a.nice())
+                        .title("Nice limits")
+                }),
+                plot_chart(|b| {
+                    b.add_series_l_with(|b| b.data([(1.0, 3.0), (2.0, 3.0)]).name("loss"))
+                        .y_scale_l(AxisScale::Log10)
+                        .title("Constant on a log axis")
+                }),
+            ],
+            3,
+        )
+    })
+    .plot_png("./dashboard.png", (1200, 640));
+}
+
+#[cfg(not(all(feature = "builder", feature = "plotters")))]
+fn main() {
+    panic!("This example requires the `builder` and `plotters` features");
+}
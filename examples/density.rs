@@ -0,0 +1,53 @@
+#[cfg(all(feature = "builder", feature = "plotters"))]
+fn main() {
+    use plot_commands::draw_command::{chart::series_ty::BinShape, plot_chart, plot_layout};
+
+    // deterministic pseudo-random points from two gaussian clusters
+    let mut state = 12345u64;
+    let mut uniform = move || {
+        state = state
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        ((state >> 40) as f32 + 0.5) / (1u64 << 24) as f32
+    };
+    let mut gaussian = move || {
+        let (u, v) = (uniform(), uniform());
+        let r = (-2.0 * u.ln()).sqrt();
+        let a = std::f32::consts::TAU * v;
+        (r * a.cos(), r * a.sin())
+    };
+    let points = (0..200_000)
+        .map(|i| {
+            let (a, b) = gaussian();
+            if i % 3 == 0 {
+                (3.0 + a * 0.6, 2.0 + b * 0.6)
+            } else {
+                (a + b * 0.5, b)
+            }
+        })
+        .collect::<Vec<_>>();
+
+    plot_layout(|b| {
+        b.hsplit([
+            plot_chart(|b| {
+                b.add_series_l_with(|b| b.data(points.clone()).density(BinShape::Hex))
+                    .title("Hexagonal bins")
+            }),
+            plot_chart(|b| {
+                b.add_series_l_with(|b| {
+                    b.data(points.clone())
+                        .density(BinShape::Rect)
+                        .bins(40)
+                        .log_counts()
+                })
+                .title("Rectangular bins, log counts")
+            }),
+        ])
+    })
+    .plot_png("./density.png", (1280, 480));
+}
+
+#[cfg(not(all(feature = "builder", feature = "plotters")))]
+fn main() {
+    panic!("This example requires the `builder` and `plotters` features");
+}
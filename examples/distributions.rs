@@ -0,0 +1,43 @@
+#[cfg(all(feature = "builder", feature = "plotters"))]
+fn main() {
+    use plot_commands::draw_command::{plot_chart, plot_layout};
+
+    // deterministic, skewed "latency" samples for a few builds
+    fn latencies(seed: u32, scale: f32) -> Vec<f32> {
+        (0..200)
+            .map(|i| {
+                let u = ((i * 7919 + seed * 104729) % 1000) as f32 / 1000.0;
+                scale * (1.0 + u * u * 3.0) + if i % 50 == 0 { scale * 8.0 } else { 0.0 }
+            })
+            .collect()
+    }
+
+    let groups = || {
+        [
+            ("build-1", latencies(1, 10.0)),
+            ("build-2", latencies(2, 12.0)),
+            ("build-3", latencies(3, 8.0)),
+        ]
+    };
+
+    plot_layout(|b| {
+        b.hsplit([
+            plot_chart(|b| {
+                b.add_series_l_with(|b| b.box_plot(groups()).name("latency"))
+                    .title("Box plot")
+                    .y_label_l("ms")
+            }),
+            plot_chart(|b| {
+                b.add_series_l_with(|b| b.violin(groups()).name("latency"))
+                    .title("Violin plot")
+                    .y_label_l("ms")
+            }),
+        ])
+    })
+    .plot_png("./distributions.png", (1024, 512));
+}
+
+#[cfg(not(all(feature = "builder", feature = "plotters")))]
+fn main() {
+    panic!("This example requires the `builder` and `plotters` features");
+}
@@ -0,0 +1,29 @@
+#[cfg(all(feature = "builder", feature = "plotters"))]
+fn main() {
+    use plot_commands::draw_command::plot_chart;
+
+    // deterministic, long-tailed request latencies in milliseconds
+    let latencies = (0..2000)
+        .map(|i| {
+            let u = ((i * 7919 + 104729) % 2000) as f32 / 2000.0;
+            20.0 - 15.0 * (1.0 - u).max(1e-3).ln()
+        })
+        .collect::<Vec<_>>();
+
+    plot_chart(|b| {
+        b.add_series_l_with(|b| {
+            b.ecdf(latencies)
+                .name("api")
+                .percentiles([50.0, 90.0, 99.0])
+        })
+        .title("Request latency")
+        .x_label("ms")
+        .y_label_l("fraction of requests")
+    })
+    .plot_png("./ecdf.png", (640, 480));
+}
+
+#[cfg(not(all(feature = "builder", feature = "plotters")))]
+fn main() {
+    panic!("This example requires the `builder` and `plotters` features");
+}
@@ -0,0 +1,30 @@
+#[cfg(all(feature = "builder", feature = "plotters"))]
+fn main() {
+    use plot_commands::draw_command::plot_chart;
+
+    let measurements = [(1.0, 2.1), (2.0, 3.9), (3.0, 6.2), (4.0, 7.8), (5.0, 10.1)];
+
+    plot_chart(|b| {
+        b.add_series_l_with(|b| {
+            b.data(measurements)
+                .name("symmetric")
+                .scatter()
+                .y_err([0.3, 0.5, 0.4, 0.8, 0.6])
+        })
+        .add_series_l_with(|b| {
+            b.data(measurements.map(|(x, y)| (x, y * 0.5)))
+                .name("asymmetric")
+                .x_err([0.1; 5])
+                .y_err([(0.2, 0.6), (0.1, 0.3), (0.4, 0.2), (0.3, 0.3), (0.5, 0.1)])
+        })
+        .title("Error bars")
+        .x_label("x")
+        .y_label_l("y")
+    })
+    .plot_png("./error_bars.png", (512, 512));
+}
+
+#[cfg(not(all(feature = "builder", feature = "plotters")))]
+fn main() {
+    panic!("This example requires the `builder` and `plotters` features");
+}
@@ -0,0 +1,41 @@
+#[cfg(all(feature = "builder", feature = "plotters"))]
+fn main() {
+    use plot_commands::{colors::colormap::Colormap, draw_command::plot_chart};
+
+    let rows = 30;
+    let cols = 40;
+    let values = (0..rows)
+        .map(|row| {
+            let y = row as f32 / rows as f32 * 4.0 - 2.0;
+            (0..cols)
+                .map(|col| {
+                    let x = col as f32 / cols as f32 * 6.0 - 3.0;
+                    (-(x * x + y * y) / 2.0).exp() + 0.3 * (2.0 * x).sin()
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    plot_chart(|b| {
+        b.add_series_l_with(|b| {
+            b.heatmap(values, (-3.0, 3.0), (-2.0, 2.0))
+                .colormap(Colormap::Magma)
+                .colorbar("intensity")
+        })
+        .add_series_l_with(|b| {
+            b.data([(-2.5, -1.5), (0.0, 0.0), (2.5, 1.5)])
+                .name("samples")
+                .scatter()
+                .color(rgb::RGBA::new(1.0, 1.0, 1.0, 1.0))
+        })
+        .title("Heatmap")
+        .x_label("x")
+        .y_label_l("y")
+    })
+    .plot_png("./heatmap.png", (640, 480));
+}
+
+#[cfg(not(all(feature = "builder", feature = "plotters")))]
+fn main() {
+    panic!("This example requires the `builder` and `plotters` features");
+}
@@ -0,0 +1,34 @@
+#[cfg(all(feature = "builder", feature = "plotters"))]
+fn main() {
+    use plot_commands::{draw_command::plot_chart, stats::Kernel};
+
+    // deterministic, bimodal "scores" for two model versions
+    fn scores(seed: u32, shift: f32) -> Vec<f32> {
+        (0..300)
+            .map(|i| {
+                let u = ((i * 7919 + seed * 104729) % 1000) as f32 / 1000.0;
+                let mode = if i % 3 == 0 { 0.3 } else { 0.65 };
+                mode + shift + (u - 0.5) * 0.25
+            })
+            .collect()
+    }
+
+    plot_chart(|b| {
+        b.add_series_l_with(|b| b.kde(scores(1, 0.0)).name("v1").rug())
+            .add_series_l_with(|b| {
+                b.kde(scores(2, 0.05))
+                    .name("v2 (epanechnikov)")
+                    .kernel(Kernel::Epanechnikov)
+                    .bandwidth(0.05)
+            })
+            .title("Score distributions")
+            .x_label("score")
+            .y_label_l("density")
+    })
+    .plot_png("./kde.png", (640, 480));
+}
+
+#[cfg(not(all(feature = "builder", feature = "plotters")))]
+fn main() {
+    panic!("This example requires the `builder` and `plotters` features");
+}
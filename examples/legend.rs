@@ -0,0 +1,69 @@
+#[cfg(all(feature = "builder", feature = "plotters"))]
+fn main() {
+    use plot_commands::draw_command::{chart::legend::LegendPosition, plot_chart, plot_layout};
+
+    let wave = |phase: f32, amplitude: f32| {
+        (0..100)
+            .map(|i| {
+                let x = i as f32 / 10.0;
+                (x, amplitude * (x + phase).sin())
+            })
+            .collect::<Vec<_>>()
+    };
+    // rising curves leave the lower right corner empty
+    let growth = |rate: f32| {
+        (0..100)
+            .map(|i| {
+                let x = i as f32 / 10.0;
+                (x, (rate * x).exp())
+            })
+            .collect::<Vec<_>>()
+    };
+
+    let waves = |b: plot_commands::draw_command::chart::chart_builder::ChartBuilder| {
+        (0..6).fold(b, |b, i| {
+            let phase = i as f32 * 0.5;
+            b.add_series_l_with(|b| b.data(wave(phase, 1.0)).name(format!("phase {phase}")))
+        })
+    };
+
+    plot_layout(|b| {
+        b.grid_with_cols(
+            [
+                plot_chart(|b| {
+                    b.add_series_l_with(|b| b.data(growth(0.3)).name("slow"))
+                        .add_series_l_with(|b| b.data(growth(0.4)).name("fast"))
+                        .add_series_l_with(|b| b.data(growth(0.35)).exclude_from_legend())
+                        .legend(LegendPosition::Best)
+                        .title("Best placement")
+                }),
+                plot_chart(|b| {
+                    b.add_series_l_with(|b| b.data(wave(0.0, 1.0)).name("signal"))
+                        .add_series_r_with(|b| b.data(wave(1.0, 50.0)).name("reference"))
+                        .decorate_with(|d| d.hline(0.5).dashed().label("threshold"))
+                        .legend(LegendPosition::LowerCenter)
+                        .legend_font_size(10.0)
+                        .title("Lower center, small font")
+                }),
+                plot_chart(|b| {
+                    waves(b)
+                        .legend(LegendPosition::OutsideRight)
+                        .title("Outside right")
+                }),
+                plot_chart(|b| {
+                    waves(b)
+                        .legend(LegendPosition::OutsideBottom)
+                        .legend_columns(3)
+                        .title("Outside bottom, three columns")
+                }),
+            ],
+            2,
+        )
+    })
+    .plot_png("./legend.png", (1024, 768));
+}
+
+#[cfg(not(all(feature = "builder", feature = "plotters")))]
+fn main() {
+    panic!("This example requires the `builder` and `plotters` features");
+}
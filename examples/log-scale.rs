@@ -0,0 +1,48 @@
+#[cfg(all(feature = "builder", feature = "plotters"))]
+fn main() {
+    use plot_commands::draw_command::{chart::axis::AxisScale, plot_chart, plot_layout};
+
+    // training loss decaying over several orders of magnitude, and a learning rate schedule
+    let loss = (1..200)
+        .map(|step| {
+            let s = step as f32;
+            (s, 3.0 * (-s / 25.0).exp() + 1e-3)
+        })
+        .collect::<Vec<_>>();
+    let learning_rate = (1..200)
+        .map(|step| (step as f32, 1e-2 * 0.97f32.powi(step)))
+        .collect::<Vec<_>>();
+
+    // a signed quantity spanning zero
+    let signed = (-50..=50)
+        .map(|i| {
+            let x = i as f32 / 10.0;
+            (x, x.powi(5))
+        })
+        .collect::<Vec<_>>();
+
+    plot_layout(|b| {
+        b.hsplit([
+            plot_chart(|b| {
+                b.add_series_l_with(|b| b.data(loss).name("loss"))
+                    .add_series_r_with(|b| b.data(learning_rate).name("learning rate"))
+                    .y_scale_l(AxisScale::Log10)
+                    .y_scale_r(AxisScale::Log10)
+                    .title("Log scales")
+                    .y_label_l("loss")
+                    .y_label_r("learning rate")
+            }),
+            plot_chart(|b| {
+                b.add_series_l_with(|b| b.data(signed).name("x^5"))
+                    .y_scale_l(AxisScale::SymLog { threshold: 1.0 })
+                    .title("Symmetric log scale")
+            }),
+        ])
+    })
+    .plot_png("./log-scale.png", (1024, 512));
+}
+
+#[cfg(not(all(feature = "builder", feature = "plotters")))]
+fn main() {
+    panic!("This example requires the `builder` and `plotters` features");
+}
@@ -0,0 +1,55 @@
+#[cfg(all(feature = "builder", feature = "plotters"))]
+fn main() {
+    use plot_commands::draw_command::{
+        chart::mesh::{LineDash, Spines, TickDirection},
+        plot_chart, plot_layout,
+    };
+
+    let signal = (0..200)
+        .map(|i| {
+            let x = i as f32 / 20.0;
+            (x, (x * 1.7).sin() * (-x / 6.0).exp())
+        })
+        .collect::<Vec<_>>();
+
+    plot_layout(|b| {
+        b.hsplit([
+            plot_chart(|b| {
+                b.add_series_l_with(|b| b.data(signal.clone()).name("default"))
+                    .x_axis_with(|a| a.minor_ticks(4))
+                    .title("Default")
+            }),
+            // light dotted major grid lines, boxed, with inward ticks
+            plot_chart(|b| {
+                b.add_series_l_with(|b| b.data(signal.clone()).name("publication"))
+                    .mesh_with(|m| {
+                        m.x_grid(true, false)
+                            .y_grid(true, false)
+                            .major_grid_style((0.0, 0.0, 0.0, 0.3), 1, LineDash::Dotted)
+                            .boxed()
+                            .tick_direction(TickDirection::In)
+                    })
+                    .title("Publication")
+            }),
+            // no grid, only a baseline
+            plot_chart(|b| {
+                b.add_series_l_with(|b| b.data(signal.clone()).name("dashboard"))
+                    .mesh_with(|m| {
+                        m.no_grid()
+                            .spines(Spines {
+                                left: false,
+                                ..Default::default()
+                            })
+                            .tick_direction(TickDirection::Hidden)
+                    })
+                    .title("Dashboard")
+            }),
+        ])
+    })
+    .plot_png("./mesh-styles.png", (1200, 400));
+}
+
+#[cfg(not(all(feature = "builder", feature = "plotters")))]
+fn main() {
+    panic!("This example requires the `builder` and `plotters` features");
+}
@@ -0,0 +1,31 @@
+#[cfg(all(feature = "builder", feature = "plotters"))]
+fn main() {
+    use plot_commands::draw_command::{plot_layout, plot_pie};
+
+    let usage = [
+        ("compute", 42.0),
+        ("storage", 23.0),
+        ("network", 17.0),
+        ("support", 11.0),
+        ("other", 7.0),
+    ];
+
+    plot_layout(|b| {
+        b.hsplit([
+            plot_pie(|b| b.slices(usage).title("Resource share")),
+            plot_pie(|b| {
+                b.slices(usage)
+                    .donut(0.5)
+                    .labels()
+                    .no_legend()
+                    .title("Donut")
+            }),
+        ])
+    })
+    .plot_png("./pie.png", (1024, 480));
+}
+
+#[cfg(not(all(feature = "builder", feature = "plotters")))]
+fn main() {
+    panic!("This example requires the `builder` and `plotters` features");
+}
@@ -0,0 +1,43 @@
+#[cfg(all(feature = "builder", feature = "plotters"))]
+fn main() {
+    use plot_commands::draw_command::{chart::polar::AngleUnit, plot_chart, plot_layout};
+
+    // radiation pattern of a simple directional antenna
+    let pattern = (0..=360)
+        .map(|deg| {
+            let theta = (deg as f32).to_radians();
+            let gain = (0.5 + 0.5 * theta.cos()).powi(2) + 0.05;
+            (deg as f32, gain * 10.0)
+        })
+        .collect::<Vec<_>>();
+
+    // events per hour of the day, in radians around the clock
+    let hours = (0..24)
+        .map(|h| {
+            let theta = std::f32::consts::TAU * h as f32 / 24.0;
+            (theta, 5.0 + 4.0 * (theta - 2.0).sin() + (h % 5) as f32)
+        })
+        .collect::<Vec<_>>();
+
+    plot_layout(|b| {
+        b.hsplit([
+            plot_chart(|b| {
+                b.add_series_l_with(|b| b.data(pattern).name("gain").fill())
+                    .polar(AngleUnit::Degrees)
+                    .title("Antenna pattern")
+            }),
+            plot_chart(|b| {
+                b.add_series_l_with(|b| b.data(hours).name("events").scatter())
+                    .polar(AngleUnit::Radians)
+                    .polar_grid(8, 4)
+                    .title("Events by time of day")
+            }),
+        ])
+    })
+    .plot_png("./polar.png", (1280, 560));
+}
+
+#[cfg(not(all(feature = "builder", feature = "plotters")))]
+fn main() {
+    panic!("This example requires the `builder` and `plotters` features");
+}
@@ -0,0 +1,27 @@
+#[cfg(all(feature = "builder", feature = "plotters"))]
+fn main() {
+    use plot_commands::draw_command::plot_chart;
+
+    // velocity field of a vortex around the origin
+    let vectors = (-5..=5)
+        .flat_map(|i| (-5..=5).map(move |j| (i as f32, j as f32)))
+        .map(|(x, y)| (x, y, -y * 0.5, x * 0.5))
+        .collect::<Vec<_>>();
+
+    plot_chart(|b| {
+        b.add_series_l_with(|b| {
+            b.name("velocity")
+                .quiver(vectors)
+                .color_by_magnitude()
+                .colorbar("speed")
+                .quiver_key(2.0, "2 m/s")
+        })
+        .title("Vortex")
+    })
+    .plot_png("./quiver.png", (640, 480));
+}
+
+#[cfg(not(all(feature = "builder", feature = "plotters")))]
+fn main() {
+    panic!("This example requires the `builder` and `plotters` features");
+}
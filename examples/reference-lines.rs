@@ -0,0 +1,51 @@
+#[cfg(all(feature = "builder", feature = "plotters"))]
+fn main() {
+    use plot_commands::draw_command::plot_chart;
+
+    // p99 latency of a service, with an incident between minutes 60 and 75
+    let latency = (0..120)
+        .map(|m| {
+            let t = m as f32;
+            let incident = if (60.0..75.0).contains(&t) {
+                180.0
+            } else {
+                0.0
+            };
+            (
+                t,
+                120.0 + 15.0 * (t / 9.0).sin() + ((m * 7919) % 17) as f32 + incident,
+            )
+        })
+        .collect::<Vec<_>>();
+
+    plot_chart(|b| {
+        b.add_series_l_with(|b| b.data(latency).name("p99 latency"))
+            .decorate_with(|d| {
+                d.vspan(60.0, 75.0)
+                    .color((0.9, 0.2, 0.2, 0.2))
+                    .label("incident")
+            })
+            .decorate_with(|d| d.hspan(100.0, 150.0).label("normal range"))
+            .decorate_with(|d| {
+                d.hline(400.0)
+                    .color((1.0, 0.0, 0.0, 1.0))
+                    .dashed()
+                    .label("SLO")
+                    .in_bounds()
+            })
+            .decorate_with(|d| {
+                d.vline(90.0)
+                    .color((0.1, 0.7, 0.1, 1.0))
+                    .label("deploy")
+                    .above()
+            })
+            .title("Checkout service")
+            .y_label_l("ms")
+    })
+    .plot_png("./reference-lines.png", (800, 480));
+}
+
+#[cfg(not(all(feature = "builder", feature = "plotters")))]
+fn main() {
+    panic!("This example requires the `builder` and `plotters` features");
+}
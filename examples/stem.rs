@@ -0,0 +1,21 @@
+#[cfg(all(feature = "builder", feature = "plotters"))]
+fn main() {
+    use plot_commands::draw_command::plot_chart;
+
+    // impulse response of a damped oscillator
+    let response = (0..30)
+        .map(|n| (-(n as f32) / 8.0).exp() * (n as f32 * 0.6).cos())
+        .collect::<Vec<_>>();
+
+    plot_chart(|b| {
+        b.add_series_l_with(|b| b.data(response).name("h[n]").stem())
+            .title("Impulse response")
+            .x_label("n")
+    })
+    .plot_png("./stem.png", (640, 480));
+}
+
+#[cfg(not(all(feature = "builder", feature = "plotters")))]
+fn main() {
+    panic!("This example requires the `builder` and `plotters` features");
+}
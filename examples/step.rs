@@ -0,0 +1,30 @@
+#[cfg(all(feature = "builder", feature = "plotters"))]
+fn main() {
+    use plot_commands::draw_command::{chart::series_ty::StepMode, plot_chart};
+
+    // a learning rate schedule that decays every 10 epochs
+    let schedule = (0..=5)
+        .map(|i| (i as f32 * 10.0, 0.1 * 0.5f32.powi(i)))
+        .collect::<Vec<_>>();
+
+    plot_chart(|b| {
+        b.add_series_l_with(|b| b.data(schedule.clone()).name("post").step(StepMode::Post))
+            .add_series_l_with(|b| b.data(schedule.clone()).name("pre").step(StepMode::Pre))
+            .add_series_l_with(|b| {
+                b.data(schedule.clone())
+                    .name("mid")
+                    .step(StepMode::Mid)
+                    .dashed()
+            })
+            .add_series_l_with(|b| b.data(schedule).name("points").scatter())
+            .title("Step lines")
+            .x_label("epoch")
+            .y_label_l("learning rate")
+    })
+    .plot_png("./step.png", (640, 480));
+}
+
+#[cfg(not(all(feature = "builder", feature = "plotters")))]
+fn main() {
+    panic!("This example requires the `builder` and `plotters` features");
+}
@@ -0,0 +1,61 @@
+#[cfg(all(feature = "builder", feature = "plotters"))]
+fn main() {
+    use plot_commands::draw_command::{chart::ticks::TickFormat, plot_chart, plot_layout};
+
+    // memory usage of a process over an hour, in bytes against seconds
+    let memory = (0..=60)
+        .map(|m| {
+            let t = m as f32 * 60.0;
+            (t, 2.0e8 + 1.1e9 * (1.0 - (-t / 900.0).exp()))
+        })
+        .collect::<Vec<_>>();
+
+    // request rate and error ratio
+    let requests = (0..24)
+        .map(|h| {
+            (
+                h as f32,
+                3.0e4
+                    + 2.5e5
+                        * ((h as f32 - 6.0) / 24.0 * std::f32::consts::TAU)
+                            .sin()
+                            .max(0.0),
+            )
+        })
+        .collect::<Vec<_>>();
+    let errors = (0..24)
+        .map(|h| (h as f32, 0.002 + 0.03 * ((h * 37) % 11) as f32 / 11.0))
+        .collect::<Vec<_>>();
+
+    plot_layout(|b| {
+        b.hsplit([
+            plot_chart(|b| {
+                b.add_series_l_with(|b| b.data(memory).name("resident memory"))
+                    .x_axis_with(|a| a.format(TickFormat::Duration).minor_ticks(3))
+                    .y_axis_l_with(|a| a.format(TickFormat::Bytes).major_ticks(6))
+                    .title("Memory")
+            }),
+            plot_chart(|b| {
+                b.add_series_l_with(|b| b.data(requests).name("requests"))
+                    .add_series_r_with(|b| b.data(errors).name("errors").dashed())
+                    .x_axis_with(|a| {
+                        a.ticks([
+                            (0.0, "midnight"),
+                            (6.0, "6am"),
+                            (12.0, "noon"),
+                            (18.0, "6pm"),
+                        ])
+                    })
+                    .y_axis_l_with(|a| a.format(TickFormat::Si))
+                    .y_axis_r_with(|a| a.format(TickFormat::Percent))
+                    .title("Traffic")
+            }),
+        ])
+    })
+    .plot_png("./tick-formats.png", (1024, 512));
+}
+
+#[cfg(not(all(feature = "builder", feature = "plotters")))]
+fn main() {
+    panic!("This example requires the `builder` and `plotters` features");
+}
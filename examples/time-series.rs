@@ -0,0 +1,68 @@
+#[cfg(all(feature = "builder", feature = "plotters", feature = "chrono"))]
+fn main() {
+    use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+    use chrono::{FixedOffset, TimeZone, Utc};
+    use plot_commands::draw_command::{plot_chart, plot_layout};
+
+    // two days of CPU usage sampled every 10 minutes, as exported by a monitoring system
+    let start = UNIX_EPOCH + Duration::from_secs(1_717_200_000);
+    let cpu = (0..288)
+        .map(|i| {
+            let t = start + Duration::from_secs(i * 600);
+            let hour = (i as f32 / 6.0) % 24.0;
+            let load = 40.0 + 30.0 * ((hour - 8.0) / 24.0 * std::f32::consts::TAU).sin();
+            (t, load + ((i * 7919) % 13) as f32)
+        })
+        .collect::<Vec<(SystemTime, f32)>>();
+
+    // a year of daily active users
+    let users = (0..365)
+        .map(|day| {
+            let t =
+                Utc.with_ymd_and_hms(2023, 7, 1, 0, 0, 0).unwrap() + chrono::Duration::days(day);
+            (
+                t,
+                1000.0 + day as f32 * 3.0 + ((day * 31) % 17) as f32 * 10.0,
+            )
+        })
+        .collect::<Vec<_>>();
+
+    // a request burst over a couple of minutes
+    let burst = (0..120)
+        .map(|s| {
+            (
+                1_717_200_000 + s as i64,
+                (s as f32 / 20.0).sin().abs() * 500.0,
+            )
+        })
+        .collect::<Vec<_>>();
+
+    plot_layout(|b| {
+        b.vsplit([
+            plot_chart(|b| {
+                b.add_series_l_with(|b| b.time_data(cpu).name("cpu %"))
+                    .time_zone(FixedOffset::east_opt(2 * 3600).unwrap())
+                    .title("CPU usage (UTC+2)")
+            }),
+            plot_layout(|b| {
+                b.hsplit([
+                    plot_chart(|b| {
+                        b.add_series_l_with(|b| b.time_data(users).name("daily active users"))
+                            .title("Users")
+                    }),
+                    plot_chart(|b| {
+                        b.add_series_l_with(|b| b.time_data(burst).name("requests/s"))
+                            .title("Request burst")
+                    }),
+                ])
+            }),
+        ])
+    })
+    .plot_png("./time-series.png", (1024, 768));
+}
+
+#[cfg(not(all(feature = "builder", feature = "plotters", feature = "chrono")))]
+fn main() {
+    panic!("This example requires the `builder`, `plotters` and `chrono` features");
+}
@@ -0,0 +1,266 @@
+use std::collections::HashMap;
+
+/// A cell of a 2-D histogram, given by its outline and the number of points inside it.
+#[derive(Debug, Clone)]
+pub struct Bin {
+    pub polygon: Vec<(f32, f32)>,
+    pub count: usize,
+}
+
+/// Returns the points with finite coordinates.
+fn finite(points: &[(f32, f32)]) -> impl Iterator<Item = &(f32, f32)> {
+    points
+        .iter()
+        .filter(|(x, y)| x.is_finite() && y.is_finite())
+}
+
+/// Returns the minimum and maximum of the points along each axis.
+fn extents(points: impl Iterator<Item = (f32, f32)>) -> Option<((f32, f32), (f32, f32))> {
+    points.fold(None, |acc, (x, y)| {
+        Some(acc.map_or(((x, x), (y, y)), |((x0, x1), (y0, y1))| {
+            ((x0.min(x), x1.max(x)), (y0.min(y), y1.max(y)))
+        }))
+    })
+}
+
+/// Maps data coordinates onto a space where the points span `[0, n]` along both axes.
+#[derive(Debug, Clone, Copy)]
+struct Normalization {
+    origin: (f32, f32),
+    cell: (f32, f32),
+}
+
+impl Normalization {
+    fn new(points: &[(f32, f32)], n: usize) -> Option<Self> {
+        let ((x0, x1), (y0, y1)) = extents(finite(points).copied())?;
+        let n = n.max(1) as f32;
+        // a cloud with no spread along an axis still gets cells of a unit size
+        let sx = if x1 > x0 { (x1 - x0) / n } else { 1.0 / n };
+        let sy = if y1 > y0 { (y1 - y0) / n } else { 1.0 / n };
+        Some(Self {
+            origin: (x0, y0),
+            cell: (sx, sy),
+        })
+    }
+
+    fn to_cells(self, (x, y): (f32, f32)) -> (f32, f32) {
+        (
+            (x - self.origin.0) / self.cell.0,
+            (y - self.origin.1) / self.cell.1,
+        )
+    }
+
+    fn to_data(self, (u, v): (f32, f32)) -> (f32, f32) {
+        (
+            self.origin.0 + u * self.cell.0,
+            self.origin.1 + v * self.cell.1,
+        )
+    }
+}
+
+/// Returns the cell of an `n` by `n` rectangular grid a point normalized by `norm` falls into.
+fn rect_cell(norm: Normalization, n: usize, p: (f32, f32)) -> (i64, i64) {
+    let (u, v) = norm.to_cells(p);
+    // points on the upper edge belong to the last cell
+    let last = n.max(1) as i64 - 1;
+    let col = (u.floor() as i64).min(last);
+    let row = (v.floor() as i64).min(last);
+    (col, row)
+}
+
+/// Counts the finite points falling into each of `n` by `n` rectangular cells spanning the
+/// points' extents. Empty cells are omitted.
+pub fn rect_bins(points: &[(f32, f32)], n: usize) -> Vec<Bin> {
+    let Some(norm) = Normalization::new(points, n) else {
+        return Vec::new();
+    };
+
+    let mut counts = HashMap::<(i64, i64), usize>::new();
+    for &p in finite(points) {
+        *counts.entry(rect_cell(norm, n, p)).or_default() += 1;
+    }
+
+    counts
+        .into_iter()
+        .map(|((col, row), count)| {
+            let (u, v) = (col as f32, row as f32);
+            Bin {
+                polygon: [(u, v), (u + 1.0, v), (u + 1.0, v + 1.0), (u, v + 1.0)]
+                    .map(|p| norm.to_data(p))
+                    .to_vec(),
+                count,
+            }
+        })
+        .collect()
+}
+
+/// Returns the minimum and maximum corners of the cells [`rect_bins`] would return, without
+/// counting the points.
+pub fn rect_bins_extent(points: &[(f32, f32)], n: usize) -> Option<((f32, f32), (f32, f32))> {
+    let norm = Normalization::new(points, n)?;
+    let cells = finite(points).map(|&p| {
+        let (col, row) = rect_cell(norm, n, p);
+        (col as f32, row as f32)
+    });
+    let ((u0, u1), (v0, v1)) = extents(cells)?;
+    let lo = norm.to_data((u0, v0));
+    let hi = norm.to_data((u1 + 1.0, v1 + 1.0));
+    Some((lo, hi))
+}
+
+// Pointy-top hexagons of unit width. Their centers form two offset rectangular lattices, and
+// the closest center of either lattice gives the hexagon a point falls into.
+const HEX_ROW_HEIGHT: f32 = 1.732_050_8;
+/// Distance from the center of a hexagon to its corners.
+const HEX_RADIUS: f32 = 1.0 / HEX_ROW_HEIGHT;
+
+/// Returns the hexagon a point normalized by `norm` falls into, as its index in one of the two
+/// lattices and whether it belongs to the offset one.
+fn hex_cell(norm: Normalization, p: (f32, f32)) -> (i64, i64, bool) {
+    let (u, v) = norm.to_cells(p);
+    let a = (u.round(), (v / HEX_ROW_HEIGHT).round());
+    let b = ((u - 0.5).round(), (v / HEX_ROW_HEIGHT - 0.5).round());
+    let dist_a = (u - a.0).powi(2) + (v - a.1 * HEX_ROW_HEIGHT).powi(2);
+    let dist_b = (u - b.0 - 0.5).powi(2) + (v - (b.1 + 0.5) * HEX_ROW_HEIGHT).powi(2);
+    if dist_a <= dist_b {
+        (a.0 as i64, a.1 as i64, false)
+    } else {
+        (b.0 as i64, b.1 as i64, true)
+    }
+}
+
+/// Returns the center of a hexagon given by [`hex_cell`], in cell units.
+fn hex_center((i, j, offset): (i64, i64, bool)) -> (f32, f32) {
+    let shift = if offset { 0.5 } else { 0.0 };
+    (i as f32 + shift, (j as f32 + shift) * HEX_ROW_HEIGHT)
+}
+
+/// Counts the finite points falling into each cell of a hexagonal grid with `n` cells across
+/// the points' horizontal extent. Empty cells are omitted.
+pub fn hex_bins(points: &[(f32, f32)], n: usize) -> Vec<Bin> {
+    let Some(norm) = Normalization::new(points, n) else {
+        return Vec::new();
+    };
+
+    let mut counts = HashMap::<(i64, i64, bool), usize>::new();
+    for &p in finite(points) {
+        *counts.entry(hex_cell(norm, p)).or_default() += 1;
+    }
+
+    counts
+        .into_iter()
+        .map(|(cell, count)| {
+            let (cu, cv) = hex_center(cell);
+            let polygon = (0..6)
+                .map(|k| {
+                    let angle = (30.0 + 60.0 * k as f32).to_radians();
+                    norm.to_data((cu + HEX_RADIUS * angle.cos(), cv + HEX_RADIUS * angle.sin()))
+                })
+                .collect();
+            Bin { polygon, count }
+        })
+        .collect()
+}
+
+/// Returns the minimum and maximum corners of the cells [`hex_bins`] would return, without
+/// counting the points.
+pub fn hex_bins_extent(points: &[(f32, f32)], n: usize) -> Option<((f32, f32), (f32, f32))> {
+    let norm = Normalization::new(points, n)?;
+    let ((u0, u1), (v0, v1)) = extents(finite(points).map(|&p| hex_center(hex_cell(norm, p))))?;
+    // the hexagons reach half a cell to the sides of their centers and a radius above and below
+    let lo = norm.to_data((u0 - 0.5, v0 - HEX_RADIUS));
+    let hi = norm.to_data((u1 + 0.5, v1 + HEX_RADIUS));
+    Some((lo, hi))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Returns each bin's lower left corner with its count, in order.
+    fn corners(bins: &[Bin]) -> Vec<((f32, f32), usize)> {
+        let mut corners = bins
+            .iter()
+            .map(|bin| {
+                let corner = bin
+                    .polygon
+                    .iter()
+                    .fold((f32::INFINITY, f32::INFINITY), |(x, y), p| {
+                        (x.min(p.0), y.min(p.1))
+                    });
+                (corner, bin.count)
+            })
+            .collect::<Vec<_>>();
+        corners.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        corners
+    }
+
+    #[test]
+    fn rect_bins_count_points_per_cell() {
+        let points = [
+            (0.0, 0.0),
+            (1.0, 1.0),
+            (2.0, 2.0),
+            (2.0, 0.0),
+            (f32::NAN, 1.0),
+        ];
+        assert_eq!(
+            corners(&rect_bins(&points, 2)),
+            vec![((0.0, 0.0), 1), ((1.0, 0.0), 1), ((1.0, 1.0), 2)]
+        );
+        assert_eq!(rect_bins_extent(&points, 2), Some(((0.0, 0.0), (2.0, 2.0))));
+    }
+
+    #[test]
+    fn zero_bins_count_as_one() {
+        let points = [(0.0, 0.0), (1.0, 1.0), (2.0, 2.0)];
+        assert_eq!(corners(&rect_bins(&points, 0)), vec![((0.0, 0.0), 3)]);
+        assert_eq!(rect_bins_extent(&points, 0), Some(((0.0, 0.0), (2.0, 2.0))));
+        let bins = hex_bins(&points, 0);
+        assert_eq!(bins.iter().map(|bin| bin.count).sum::<usize>(), 3);
+    }
+
+    #[test]
+    fn a_single_point_gets_a_cell() {
+        let bins = rect_bins(&[(3.0, 5.0)], 4);
+        assert_eq!(corners(&bins), vec![((3.0, 5.0), 1)]);
+        let bins = hex_bins(&[(3.0, 5.0)], 4);
+        assert_eq!(bins.len(), 1);
+        assert_eq!(bins[0].count, 1);
+    }
+
+    #[test]
+    fn hex_bins_count_every_finite_point() {
+        let points = (0..100)
+            .map(|i| {
+                let t = i as f32 * 0.37;
+                (t.sin() * 10.0, t.cos() * 4.0 + t)
+            })
+            .chain([(f32::INFINITY, 0.0)])
+            .collect::<Vec<_>>();
+        let bins = hex_bins(&points, 6);
+        assert_eq!(bins.iter().map(|bin| bin.count).sum::<usize>(), 100);
+
+        let ((x0, y0), (x1, y1)) = hex_bins_extent(&points, 6).unwrap();
+        let eps = 1e-4;
+        let (mut lo, mut hi) = (
+            (f32::INFINITY, f32::INFINITY),
+            (f32::NEG_INFINITY, f32::NEG_INFINITY),
+        );
+        for &(x, y) in bins.iter().flat_map(|bin| &bin.polygon) {
+            lo = (lo.0.min(x), lo.1.min(y));
+            hi = (hi.0.max(x), hi.1.max(y));
+        }
+        assert!((lo.0 - x0).abs() < eps && (lo.1 - y0).abs() < eps);
+        assert!((hi.0 - x1).abs() < eps && (hi.1 - y1).abs() < eps);
+    }
+
+    #[test]
+    fn no_finite_points_give_no_bins() {
+        let points = [(f32::NAN, 0.0)];
+        assert!(rect_bins(&points, 4).is_empty());
+        assert!(hex_bins(&[], 4).is_empty());
+        assert_eq!(rect_bins_extent(&points, 4), None);
+        assert_eq!(hex_bins_extent(&[], 4), None);
+    }
+}
@@ -0,0 +1,258 @@
+use std::ops::Range;
+
+use plotters::coord::{
+    ranged1d::{KeyPointHint, KeyPointWeight, NoDefaultFormatting, Ranged, ValueFormatter},
+    types::RangedCoordf32,
+};
+
+use crate::draw_command::chart::{
+    axis::{nice_step, AxisScale},
+    ticks::{TickFormat, Ticks},
+    time::{TimeAxis, TimeStep, TimeUnit},
+};
+
+/// A plotters coordinate that places values along an axis according to its scale. Linear axes
+/// behave exactly like `RangedCoordf32`.
+#[derive(Clone)]
+pub struct AxisCoord {
+    scale: AxisScale,
+    linear: RangedCoordf32,
+    /// The range's limits mapped by the scale.
+    forward: (f32, f32),
+    inverted: bool,
+    /// The time axis and the spacing of its ticks, for axes showing time.
+    time: Option<(TimeAxis, TimeStep)>,
+    ticks: Ticks,
+}
+
+impl AxisCoord {
+    /// Creates a coordinate running from `start` to `end`, which is inverted when `end` is the
+    /// smaller of the two.
+    pub fn new(scale: AxisScale, (start, end): (f32, f32)) -> Self {
+        let inverted = end < start;
+        let (lo, hi) = if inverted { (end, start) } else { (start, end) };
+        Self {
+            scale,
+            linear: (lo..hi).into(),
+            forward: (scale.forward(lo), scale.forward(hi)),
+            inverted,
+            time: None,
+            ticks: Ticks::default(),
+        }
+    }
+
+    /// Places and labels the ticks according to `ticks`.
+    pub fn with_ticks(mut self, ticks: Ticks) -> Self {
+        self.ticks = ticks;
+        self
+    }
+
+    /// Creates a linear coordinate whose ticks are aligned to the calendar, placing at most
+    /// `max_ticks` of them.
+    pub fn time(time: TimeAxis, range: (f32, f32), max_ticks: usize) -> Self {
+        Self {
+            time: Some((time, time.step(range, max_ticks))),
+            ..Self::linear(range)
+        }
+    }
+
+    pub fn linear(range: (f32, f32)) -> Self {
+        Self::new(AxisScale::Linear, range)
+    }
+
+    /// Returns the number of characters of the longest of at most `max_labels` tick labels.
+    pub fn label_chars(&self, max_labels: usize) -> usize {
+        self.key_points(max_labels)
+            .iter()
+            .map(|v| self.format_ext(v).chars().count())
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Returns multiples of a step of seconds, minutes, hours or days within the range, for
+    /// durations in seconds.
+    fn duration_points(&self, max_points: usize) -> Vec<f32> {
+        let Range { start, end } = self.linear.range();
+        let mut step = TimeStep::for_span((end - start) as f64, max_points);
+        if matches!(step.unit, TimeUnit::Month | TimeUnit::Year) {
+            step = TimeStep {
+                unit: TimeUnit::Day,
+                count: (step.approx_seconds() / 86400.0).round() as i64,
+            };
+        }
+        self.multiples(step.approx_seconds() as f32)
+    }
+
+    /// Returns nice multiples of the largest binary unit (KiB, MiB, ...) that fits in a step,
+    /// for sizes in bytes.
+    fn byte_points(&self, max_points: usize) -> Vec<f32> {
+        let Range { start, end } = self.linear.range();
+        let raw = (end - start) / max_points.max(1) as f32;
+        let mut unit = 1.0;
+        while raw / (unit * 1024.0) >= 1.0 {
+            unit *= 1024.0;
+        }
+        self.multiples(nice_step(raw / unit) * unit)
+    }
+
+    fn multiples(&self, step: f32) -> Vec<f32> {
+        let Range { start, end } = self.linear.range();
+        let first = (start / step).ceil() as i64;
+        (first..)
+            .map(|i| i as f32 * step)
+            .take_while(|v| *v <= end)
+            .collect()
+    }
+
+    fn contains(&self, value: f32) -> bool {
+        let Range { start, end } = self.linear.range();
+        (start.min(end)..=start.max(end)).contains(&value)
+    }
+
+    /// Returns powers of the scale's base within the range, and with `minor`, the integer
+    /// multiples of those powers in between. Symmetric-log scales mirror the powers above their
+    /// threshold around zero.
+    fn log_points(&self, base: f32, minor: bool) -> Vec<f32> {
+        let Range { start, end } = self.linear.range();
+        let (f0, f1) = self.forward;
+        let (k0, k1) = match self.scale.linear_threshold() {
+            Some(threshold) => {
+                let magnitude = start.abs().max(end.abs()).max(threshold);
+                (threshold.log10().floor(), magnitude.log10().ceil())
+            }
+            _ => (f0.min(f1).floor(), f0.max(f1).ceil()),
+        };
+
+        let multiples = if minor && base == 10.0 { 1..10 } else { 1..2 };
+        let mut points = (k0 as i32..=k1 as i32)
+            .flat_map(|k| multiples.clone().map(move |m| base.powi(k) * m as f32))
+            .collect::<Vec<_>>();
+
+        if let Some(threshold) = self.scale.linear_threshold() {
+            points.retain(|p| *p >= threshold);
+            points = points.iter().flat_map(|p| [-p, *p]).collect();
+            points.push(0.0);
+            points.sort_by(f32::total_cmp);
+        }
+        points.retain(|p| self.contains(*p));
+        points
+    }
+}
+
+/// Formats a value in scientific notation without trailing zeros, such as `1e-4` or `2.5e6`.
+fn format_scientific(value: f32) -> String {
+    let s = format!("{value:.3e}");
+    match s.split_once('e') {
+        Some((mantissa, exponent)) => {
+            let mantissa = mantissa.trim_end_matches('0').trim_end_matches('.');
+            format!("{mantissa}e{exponent}")
+        }
+        None => s,
+    }
+}
+
+/// Keeps every `n`-th point, so that at most `max` remain.
+fn thin(points: Vec<f32>, max: usize) -> Vec<f32> {
+    let stride = points.len().div_ceil(max.max(1)).max(1);
+    points.into_iter().step_by(stride).collect()
+}
+
+impl Ranged for AxisCoord {
+    type FormatOption = NoDefaultFormatting;
+    type ValueType = f32;
+
+    fn map(&self, value: &f32, limit: (i32, i32)) -> i32 {
+        let limit = if self.inverted {
+            (limit.1, limit.0)
+        } else {
+            limit
+        };
+        if self.scale == AxisScale::Linear {
+            return self.linear.map(value, limit);
+        }
+
+        let (f0, f1) = self.forward;
+        let v = self.scale.forward(*value);
+        // values outside of the scale's domain are placed far off the chart
+        let v = if v.is_finite() {
+            v
+        } else {
+            f0 - (f1 - f0) * 10.0
+        };
+        let t = if f1 != f0 { (v - f0) / (f1 - f0) } else { 0.5 };
+        limit.0 + (t * (limit.1 - limit.0) as f32).round() as i32
+    }
+
+    fn key_points<Hint: KeyPointHint>(&self, hint: Hint) -> Vec<f32> {
+        if !self.ticks.explicit.is_empty() {
+            return self
+                .ticks
+                .explicit
+                .iter()
+                .map(|tick| tick.value)
+                .filter(|v| self.contains(*v))
+                .collect();
+        }
+        if let Some((time, step)) = &self.time {
+            let Range { start, end } = self.linear.range();
+            return time.ticks((start, end), *step);
+        }
+
+        if self.scale == AxisScale::Linear {
+            match self.ticks.format {
+                TickFormat::Duration => return self.duration_points(hint.max_num_points()),
+                TickFormat::Bytes => return self.byte_points(hint.max_num_points()),
+                _ => {}
+            }
+        }
+
+        let Some(base) = self.scale.base() else {
+            return self.linear.key_points(hint);
+        };
+
+        let major = self.log_points(base, false);
+        // a range within a single order of magnitude has too few powers to be labeled
+        if major.len() < 2 {
+            return self.linear.key_points(hint);
+        }
+
+        if matches!(hint.weight(), KeyPointWeight::Any) {
+            let minor = self.log_points(base, true);
+            if minor.len() <= hint.max_num_points() {
+                return minor;
+            }
+        }
+        thin(major, hint.bold_points())
+    }
+
+    fn range(&self) -> Range<f32> {
+        self.linear.range()
+    }
+}
+
+impl ValueFormatter<f32> for AxisCoord {
+    fn format_ext(&self, value: &f32) -> String {
+        let v = *value;
+        if let Some(label) = self.ticks.label(v) {
+            return label;
+        }
+        if let Some((time, step)) = &self.time {
+            return time.format(v, *step);
+        }
+
+        match self.scale {
+            AxisScale::Linear => RangedCoordf32::format(value),
+            _ if v == 0.0 => "0".to_string(),
+            AxisScale::Ln => {
+                let k = v.ln();
+                if (k - k.round()).abs() < 1e-3 {
+                    format!("e^{}", k.round())
+                } else {
+                    RangedCoordf32::format(value)
+                }
+            }
+            _ if v.abs() >= 1e5 || v.abs() < 1e-3 => format_scientific(v),
+            _ => RangedCoordf32::format(value),
+        }
+    }
+}
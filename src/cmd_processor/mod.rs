@@ -1,5 +1,7 @@
 use crate::draw_command::DrawComand;
 
+#[cfg(feature = "plotters")]
+mod axis_coord;
 #[cfg(feature = "plotters")]
 pub mod plotters;
 
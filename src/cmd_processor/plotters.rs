@@ -1,29 +1,59 @@
 use image::imageops::FilterType;
 use rgb::RGBA;
-use std::path::PathBuf;
+use std::{ops::Range, path::PathBuf};
 
-use itertools::{chain, izip};
+use itertools::{chain, izip, Itertools};
 use plotters::{
     backend::{BitMapBackend, DrawingBackend},
-    chart::{ChartBuilder, ChartContext, LabelAreaPosition, SeriesAnno, SeriesLabelStyle},
-    coord::{types::RangedCoordf32, CoordTranslate, Shift},
+    chart::{ChartBuilder, ChartContext, LabelAreaPosition},
+    coord::{
+        ranged1d::{BoldPoints, LightPoints, Ranged},
+        Shift,
+    },
     drawing::{DrawingArea, IntoDrawingArea},
-    element::{BitMapElement, Circle, PathElement},
+    element::{
+        BitMapElement, CandleStick, Circle, ErrorBar, PathElement, Polygon, Rectangle, Text,
+    },
     prelude::Cartesian2d,
     series::{DashedLineSeries, LineSeries},
-    style::{Color, RGBAColor, ShapeStyle},
+    style::{
+        text_anchor::{HPos, Pos, VPos},
+        Color, FontTransform, IntoFont, RGBAColor, RGBColor, ShapeStyle, TextStyle, TRANSPARENT,
+    },
 };
 
 use crate::{
+    binning::Bin,
+    colors::colormap::Colormap,
+    contour::{iso_band, iso_lines},
     data_bound::DataBound,
     draw_command::{
-        chart::{chart::Chart, series::ChartSeries},
+        chart::{
+            annotation::{Annotation, AnnotationKind, HAlign, VAlign},
+            axis::{nice_step, Axis},
+            chart::Chart,
+            color_scale::Colorbar,
+            decoration::{Decoration, DecorationKind, ZOrder},
+            legend::LegendPosition,
+            mesh::{GridLines, LineDash, Mesh, Spines, TickDirection},
+            polar::Polar,
+            series::ChartSeries,
+            series_ty::{
+                ChartSeriesBoxConfig, ChartSeriesCandlestickConfig, ChartSeriesContourConfig,
+                ChartSeriesDensityConfig, ChartSeriesEcdfConfig, ChartSeriesHeatmapConfig,
+                ChartSeriesKdeConfig, ChartSeriesQuiverConfig, ChartSeriesScatterConfig,
+                ChartSeriesType as CST, ChartSeriesViolinConfig,
+            },
+        },
+        chart3d::{Chart3D, Chart3DSeriesType},
         layout::Layout,
+        pie::{Pie, PieSlice},
         DrawComand,
     },
+    stats::{gaussian_kde, linspace, scott_bandwidth, sorted_finite, BoxStats},
 };
 
-use super::CmdProcessor;
+use super::{axis_coord::AxisCoord, CmdProcessor};
 
 fn convert_color(RGBA { r, g, b, a }: RGBA<f32>) -> RGBAColor {
     RGBAColor(
@@ -42,6 +72,286 @@ fn convert_style(style: crate::draw_command::chart::series_style::SeriesStyle) -
     }
 }
 
+/// Horizontal space taken by each colorbar, including its labels.
+const COLORBAR_WIDTH: u32 = 90;
+
+/// Approximate width of a character of axis labels.
+const CHAR_WIDTH: u32 = 7;
+
+/// Returns the triangle of an arrow head pointing at `to`, in pixel coordinates.
+fn arrow_head(from: (i32, i32), to: (i32, i32), size: f64) -> Vec<(i32, i32)> {
+    let (dx, dy) = ((to.0 - from.0) as f64, (to.1 - from.1) as f64);
+    let len = dx.hypot(dy);
+    if len == 0.0 {
+        return Vec::new();
+    }
+    // keep the head shorter than the shaft
+    let size = size.min(len * 0.5);
+    let (ux, uy) = (dx / len, dy / len);
+    let (bx, by) = (to.0 as f64 - ux * size, to.1 as f64 - uy * size);
+    let (px, py) = (-uy * size * 0.5, ux * size * 0.5);
+    vec![
+        to,
+        ((bx + px).round() as i32, (by + py).round() as i32),
+        ((bx - px).round() as i32, (by - py).round() as i32),
+    ]
+}
+
+/// Returns the bins of a density series, which its colorbar and its drawing share so that the
+/// points are only binned once. Other series have none.
+fn density_cells(series: &ChartSeries) -> Vec<Bin> {
+    match &series.chart_series_type {
+        CST::Density(d) => d.bin(&series.data),
+        _ => Vec::new(),
+    }
+}
+
+/// Formats a contour level or a labeled value compactly, without trailing zeros.
+fn format_level(level: f32) -> String {
+    let s = format!("{level:.3}");
+    let s = s.trim_end_matches('0').trim_end_matches('.');
+    s.to_string()
+}
+
+/// The number of labels plotters places on an axis by default.
+const DEFAULT_LABELS: usize = 11;
+
+/// Length of tick marks, which plotters also spaces the axis labels by.
+const TICK_SIZE: i32 = 5;
+
+/// Returns the values of an axis' major ticks, of which at most `major` are labeled, and of
+/// the minor ticks between them, at most `minor` between two major ones.
+fn tick_values(coord: &AxisCoord, major: usize, minor: usize) -> (Vec<f32>, Vec<f32>) {
+    let major_values = coord.key_points(BoldPoints(major));
+    let minor_values = coord
+        .key_points(LightPoints::new(major, major * minor))
+        .into_iter()
+        .filter(|v| !major_values.contains(v))
+        .collect();
+    (major_values, minor_values)
+}
+
+/// Splits a polyline in pixels into the pieces stroked with `dash`, for lines `width` wide.
+fn dash_segments(points: Vec<(i32, i32)>, dash: LineDash, width: u32) -> Vec<Vec<(i32, i32)>> {
+    let w = width.max(1) as f64;
+    let (on, off) = match dash {
+        LineDash::Solid => return vec![points],
+        LineDash::Dashed => (6.0 * w, 4.0 * w),
+        LineDash::Dotted => (w, 3.0 * w),
+    };
+
+    let mut segments = vec![];
+    let mut current = points.first().into_iter().copied().collect_vec();
+    let mut drawing = true;
+    // the length left of the current dash or gap
+    let mut left = on;
+    for (a, b) in points.into_iter().tuple_windows() {
+        let (dx, dy) = ((b.0 - a.0) as f64, (b.1 - a.1) as f64);
+        let len = dx.hypot(dy);
+        let mut t = 0.0;
+        while len - t > left {
+            t += left;
+            let p = (
+                a.0 + (dx * t / len).round() as i32,
+                a.1 + (dy * t / len).round() as i32,
+            );
+            if drawing {
+                current.push(p);
+                // the backend drops the last pixel of straight lines drawn upwards or leftwards,
+                // which erases dots, so they are drawn the other way
+                if current.len() == 2 {
+                    current.sort();
+                }
+                segments.push(std::mem::take(&mut current));
+                left = off;
+            } else {
+                current = vec![p];
+                left = on;
+            }
+            drawing = !drawing;
+        }
+        left -= len - t;
+        if drawing {
+            current.push(b);
+        }
+    }
+    if drawing && current.len() > 1 {
+        segments.push(current);
+    }
+    segments
+}
+
+/// Width of the line or box drawn before each legend label.
+const LEGEND_GLYPH: u32 = 20;
+
+/// Padding inside the legend's box, gap between its columns, and distance from the plotting
+/// area.
+const LEGEND_PAD: u32 = 8;
+
+/// An entry of a chart's legend.
+struct LegendEntry {
+    label: String,
+    color: RGBAColor,
+    /// Draws a box instead of a line, for bands.
+    filled: bool,
+}
+
+/// Returns the legend entries of the chart's named series and labeled decorations, leaving out
+/// the series a polar chart does not draw.
+fn legend_entries(chart_cmd: &Chart) -> Vec<LegendEntry> {
+    let polar = chart_cmd.polar.is_some();
+    let series = chain!(&chart_cmd.series_l, &chart_cmd.series_r)
+        .filter(|series| !series.name.is_empty() && !series.exclude_from_legend)
+        .filter(|series| !polar || series.chart_series_type.supports_polar())
+        .map(|series| LegendEntry {
+            label: series.name.clone(),
+            color: convert_color(series.style.color),
+            filled: false,
+        });
+    let decorations = chart_cmd
+        .decorations
+        .iter()
+        .filter(|decoration| !decoration.label.is_empty())
+        .map(|decoration| LegendEntry {
+            label: decoration.label.clone(),
+            color: convert_color(decoration.color),
+            filled: matches!(
+                decoration.kind,
+                DecorationKind::HSpan { .. } | DecorationKind::VSpan { .. }
+            ),
+        });
+    series.chain(decorations).collect()
+}
+
+/// The entries of a legend, laid out in pixels.
+struct LegendLayout {
+    entries: Vec<LegendEntry>,
+    style: TextStyle<'static>,
+    /// The width of each column, including the glyphs.
+    columns: Vec<u32>,
+    row_height: u32,
+}
+
+impl LegendLayout {
+    /// Measures the chart's legend entries, or returns `None` when it has none.
+    fn new(area: &DrawingArea<impl DrawingBackend, Shift>, chart_cmd: &Chart) -> Option<Self> {
+        let entries = legend_entries(chart_cmd);
+        if entries.is_empty() {
+            return None;
+        }
+
+        let legend = &chart_cmd.legend;
+        let style = TextStyle::from(("sans-serif", legend.font_size as f64).into_font())
+            .pos(Pos::new(HPos::Left, VPos::Center));
+        let mut columns = vec![0; legend.columns.clamp(1, entries.len())];
+        let mut text_height = 0;
+        for (i, entry) in entries.iter().enumerate() {
+            let (w, h) = area
+                .estimate_text_size(&entry.label, &style)
+                .unwrap_or_default();
+            let column = i % columns.len();
+            columns[column] = columns[column].max(LEGEND_GLYPH + LEGEND_PAD / 2 + w);
+            text_height = text_height.max(h);
+        }
+        let row_height = text_height.max(legend.font_size as u32) + LEGEND_PAD / 2;
+
+        Some(Self {
+            entries,
+            style,
+            columns,
+            row_height,
+        })
+    }
+
+    /// Returns the size of the legend's box.
+    fn size(&self) -> (u32, u32) {
+        let rows = self.entries.len().div_ceil(self.columns.len()) as u32;
+        let w = self.columns.iter().sum::<u32>() + LEGEND_PAD * (self.columns.len() as u32 + 1);
+        (w, rows * self.row_height + LEGEND_PAD)
+    }
+
+    /// Returns the top left corner of the legend placed at an inside `position` of the
+    /// plotting area, whose pixel ranges are `plot`.
+    fn corner(&self, position: LegendPosition, (x, y): (Range<i32>, Range<i32>)) -> (i32, i32) {
+        use LegendPosition as LP;
+        let (h, v) = match position {
+            LP::UpperLeft | LP::Best | LP::OutsideRight | LP::OutsideBottom => (0, 0),
+            LP::UpperCenter => (1, 0),
+            LP::UpperRight => (2, 0),
+            LP::CenterLeft => (0, 1),
+            LP::Center => (1, 1),
+            LP::CenterRight => (2, 1),
+            LP::LowerLeft => (0, 2),
+            LP::LowerCenter => (1, 2),
+            LP::LowerRight => (2, 2),
+        };
+        let place = |range: Range<i32>, size: u32, at: u8| match at {
+            0 => range.start + LEGEND_PAD as i32,
+            1 => (range.start + range.end - size as i32) / 2,
+            _ => range.end - LEGEND_PAD as i32 - size as i32,
+        };
+        let (w, h_px) = self.size();
+        (place(x, w, h), place(y, h_px, v))
+    }
+
+    /// Returns the inside position where the legend covers the fewest of `points`, the
+    /// pixels of the series' drawings. Ties are broken in the order the positions are listed in.
+    fn best(&self, plot: (Range<i32>, Range<i32>), points: &[(i32, i32)]) -> LegendPosition {
+        use LegendPosition as LP;
+        let (w, h) = self.size();
+        [
+            LP::UpperRight,
+            LP::UpperLeft,
+            LP::LowerLeft,
+            LP::LowerRight,
+            LP::CenterRight,
+            LP::CenterLeft,
+            LP::LowerCenter,
+            LP::UpperCenter,
+            LP::Center,
+        ]
+        .into_iter()
+        .min_by_key(|position| {
+            let (x, y) = self.corner(*position, plot.clone());
+            points
+                .iter()
+                .filter(|(px, py)| (x..x + w as i32).contains(px) && (y..y + h as i32).contains(py))
+                .count()
+        })
+        .unwrap()
+    }
+
+    /// Draws the legend with its top left corner at `(x, y)`, relative to the area.
+    fn draw(&self, area: &DrawingArea<impl DrawingBackend, Shift>, (x, y): (i32, i32)) {
+        let (w, h) = self.size();
+        let corners = [(x, y), (x + w as i32, y + h as i32)];
+        let background = plotters::style::WHITE.mix(0.8).filled();
+        area.draw(&Rectangle::new(corners, background)).unwrap();
+        area.draw(&Rectangle::new(corners, plotters::style::BLACK))
+            .unwrap();
+
+        let (pad, glyph) = (LEGEND_PAD as i32, LEGEND_GLYPH as i32);
+        for (i, entry) in self.entries.iter().enumerate() {
+            let (column, row) = (i % self.columns.len(), i / self.columns.len());
+            let left = self.columns[..column].iter().sum::<u32>() as i32 + pad * column as i32;
+            let ex = x + pad + left;
+            let ey = y + pad / 2 + (row as u32 * self.row_height + self.row_height / 2) as i32;
+            if entry.filled {
+                let corners = [(ex, ey - 5), (ex + glyph, ey + 5)];
+                area.draw(&Rectangle::new(corners, entry.color.filled()))
+                    .unwrap();
+            } else {
+                let style = entry.color.stroke_width(2);
+                area.draw(&PathElement::new(vec![(ex, ey), (ex + glyph, ey)], style))
+                    .unwrap();
+            }
+            let at = (ex + glyph + pad / 2, ey);
+            area.draw(&Text::new(entry.label.clone(), at, &self.style))
+                .unwrap();
+        }
+    }
+}
+
 pub struct PlottersProcessor {
     pub path: PathBuf,
     pub w: u32,
@@ -49,129 +359,1546 @@ pub struct PlottersProcessor {
 }
 
 impl PlottersProcessor {
-    fn configure_series(&self, s: &mut SeriesAnno<impl DrawingBackend>, series: &ChartSeries) {
-        s.label(&series.name);
-        if !series.name.is_empty() {
-            let color = convert_color(series.style.color);
-            s.label(&series.name)
-                .legend(move |(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], color));
-        }
-    }
-
+    /// Draws a series. `cells` are the bins of a density series, see [`density_cells`], and
+    /// `arrow_size` the size automatically scaled arrows are relative to, see
+    /// [`Chart::arrow_size`].
     fn add_series(
         &self,
-        chart: &mut ChartContext<impl DrawingBackend, Cartesian2d<RangedCoordf32, RangedCoordf32>>,
+        chart: &mut ChartContext<impl DrawingBackend, Cartesian2d<AxisCoord, AxisCoord>>,
         series: &ChartSeries,
+        cells: &[Bin],
+        arrow_size: f32,
     ) {
-        use crate::draw_command::chart::series_ty::ChartSeriesType as CST;
-        match series.chart_series_type {
+        let series = &series.finite();
+        match &series.chart_series_type {
             CST::Line(line) => {
+                let data = match line.step {
+                    Some(step) => step.apply(&series.data),
+                    None => series.data.clone(),
+                };
+                if let (true, Some(first), Some(last)) = (line.filled, data.first(), data.last()) {
+                    let outline = chain!(data.iter().copied(), [(last.0, 0.0), (first.0, 0.0)]);
+                    let color = convert_color(series.style.color).mix(0.3).filled();
+                    chart
+                        .draw_series(std::iter::once(Polygon::new(outline.collect_vec(), color)))
+                        .unwrap();
+                }
                 if line.dashed {
-                    let s = DashedLineSeries::new(
-                        series.data.clone(),
-                        10,
-                        8,
-                        convert_style(series.style),
-                    );
-                    let s = chart.draw_series(s).unwrap();
-                    self.configure_series(s, series);
+                    let s = DashedLineSeries::new(data, 10, 8, convert_style(series.style));
+                    chart.draw_series(s).unwrap();
                 } else {
                     let style = series.style;
                     // TODO: configure stroke width
-                    let s =
-                        LineSeries::new(series.data.clone(), convert_style(style).stroke_width(1));
-                    let s = chart.draw_series(s).unwrap();
-                    self.configure_series(s, series);
+                    let s = LineSeries::new(data, convert_style(style).stroke_width(1));
+                    chart.draw_series(s).unwrap();
                 }
             }
-            CST::Scatter(scatter) => {
+            CST::Scatter(scatter) => self.add_scatter(chart, series, scatter),
+            CST::Stem(stem) => {
                 let color = convert_color(series.style.color);
-                let color = if scatter.filled {
+                let marker = if stem.filled {
                     color.filled()
                 } else {
                     color.into()
                 };
 
-                let s = chart
+                if let Some(bounds) = series.bounds() {
+                    let (x0, x1) = bounds.x.as_tuple();
+                    chart
+                        .draw_series(std::iter::once(PathElement::new(
+                            vec![(x0, stem.baseline), (x1, stem.baseline)],
+                            color,
+                        )))
+                        .unwrap();
+                }
+
+                chart
+                    .draw_series(
+                        series.data.iter().map(|(x, y)| {
+                            PathElement::new(vec![(*x, stem.baseline), (*x, *y)], color)
+                        }),
+                    )
+                    .unwrap();
+                chart
                     .draw_series(
                         series
                             .data
                             .iter()
-                            .map(|(x, y)| Circle::new((*x, *y), 2, color)),
+                            .map(|(x, y)| Circle::new((*x, *y), 3, marker)),
                     )
                     .unwrap();
-                self.configure_series(s, series);
             }
+            CST::Box(b) => self.add_box_plot(chart, series, b),
+            CST::Violin(v) => self.add_violin(chart, series, v),
+            CST::Kde(k) => self.add_kde(chart, series, k),
+            CST::Ecdf(e) => self.add_ecdf(chart, series, e),
+            CST::Heatmap(h) => self.add_heatmap(chart, series, h),
+            CST::Density(d) => self.add_density(chart, d, cells),
+            CST::Contour(c) => self.add_contour(chart, series, c),
+            CST::Candlestick(c) => self.add_candlestick(chart, c),
+            CST::Quiver(q) => self.add_quiver(chart, series, q, arrow_size),
         };
+
+        self.add_error_bars(chart, series);
     }
 
-    fn configure_series_labels<'a, 'b>(
+    fn add_scatter(
         &self,
-        series_labels: &mut SeriesLabelStyle<'a, 'b, impl DrawingBackend + 'a, impl CoordTranslate>,
-        chart_cmd: &Chart,
+        chart: &mut ChartContext<impl DrawingBackend, Cartesian2d<AxisCoord, AxisCoord>>,
+        series: &ChartSeries,
+        config: &ChartSeriesScatterConfig,
     ) {
-        if chain!(&chart_cmd.series_l, &chart_cmd.series_r).any(|series| !series.name.is_empty()) {
-            series_labels
-                .position(plotters::chart::SeriesLabelPosition::UpperLeft)
-                .background_style(&plotters::style::WHITE.mix(0.8))
-                .border_style(&plotters::style::BLACK);
+        let color_range = series.color_range();
+        let size_extent = config.size_extent();
+        let style = |color: RGBAColor| {
+            // overlapping bubbles stay visible through each other
+            let color = if size_extent.is_some() {
+                color.mix(0.7)
+            } else {
+                color
+            };
+            if config.filled {
+                color.filled()
+            } else {
+                color.into()
+            }
+        };
+
+        let markers = series.data.iter().enumerate().map(|(i, (x, y))| {
+            let color = match (color_range, config.values.get(i)) {
+                (Some((scale, range)), Some(value)) if value.is_finite() => {
+                    scale.color(*value, range)
+                }
+                _ => series.style.color,
+            };
+            let radius = config.point_radius(i, size_extent).round() as i32;
+            Circle::new((*x, *y), radius, style(convert_color(color)))
+        });
+        chart.draw_series(markers).unwrap();
+
+        if let (true, Some((lo, hi))) = (config.size_legend, size_extent) {
+            self.draw_size_legend(chart, series, config, (lo, hi));
         }
     }
 
+    /// Draws a legend of marker sizes in the upper right corner of the plotting area.
+    fn draw_size_legend(
+        &self,
+        chart: &ChartContext<impl DrawingBackend, Cartesian2d<AxisCoord, AxisCoord>>,
+        series: &ChartSeries,
+        config: &ChartSeriesScatterConfig,
+        (lo, hi): (f32, f32),
+    ) {
+        let sizes = if hi > lo {
+            vec![lo, (lo + hi) * 0.5, hi]
+        } else {
+            vec![lo]
+        };
+        let radii = sizes
+            .iter()
+            .map(|size| config.radius(*size, (lo, hi)).round() as i32)
+            .collect::<Vec<_>>();
+        let max_radius = radii.iter().copied().max().unwrap_or(0);
+
+        let pad = 6;
+        let text_width = 50;
+        let width = pad * 3 + max_radius * 2 + text_width;
+        let height = pad + radii.iter().map(|r| r * 2 + pad).sum::<i32>();
+
+        let area = chart.plotting_area().strip_coord_spec();
+        let (w, _) = area.dim_in_pixel();
+        let x0 = w as i32 - width - 10;
+        let y0 = 10;
+
+        area.draw(&Rectangle::new(
+            [(x0, y0), (x0 + width, y0 + height)],
+            plotters::style::WHITE.mix(0.8).filled(),
+        ))
+        .unwrap();
+        area.draw(&Rectangle::new(
+            [(x0, y0), (x0 + width, y0 + height)],
+            plotters::style::BLACK,
+        ))
+        .unwrap();
+
+        let color = convert_color(series.style.color);
+        let text =
+            TextStyle::from(("sans-serif", 12).into_font()).pos(Pos::new(HPos::Left, VPos::Center));
+        let cx = x0 + pad + max_radius;
+        let mut y = y0 + pad;
+        for (size, radius) in sizes.iter().zip(radii) {
+            let cy = y + radius;
+            area.draw(&Circle::new((cx, cy), radius, color)).unwrap();
+            area.draw(&Text::new(
+                format_level(*size),
+                (cx + max_radius + pad, cy),
+                text.clone(),
+            ))
+            .unwrap();
+            y += radius * 2 + pad;
+        }
+    }
+
+    /// Draws a polyline in pixels, relative to the area, in the style of `lines`.
+    fn draw_grid_lines(
+        &self,
+        area: &DrawingArea<impl DrawingBackend, Shift>,
+        points: Vec<(i32, i32)>,
+        lines: &GridLines,
+    ) {
+        let style = convert_color(lines.color).stroke_width(lines.width);
+        for segment in dash_segments(points, lines.dash, lines.width) {
+            area.draw(&PathElement::new(segment, style)).unwrap();
+        }
+    }
+
+    /// Draws the grid lines across the plotting area at the major and minor ticks of both axes,
+    /// as returned by [`tick_values`].
+    fn draw_grid(
+        &self,
+        area: &DrawingArea<impl DrawingBackend, Shift>,
+        chart: &ChartContext<impl DrawingBackend, Cartesian2d<AxisCoord, AxisCoord>>,
+        mesh: &Mesh,
+        (x_major, x_minor): &(Vec<f32>, Vec<f32>),
+        (y_major, y_minor): &(Vec<f32>, Vec<f32>),
+    ) {
+        let (base_x, base_y) = area.get_base_pixel();
+        let (x, y) = chart.plotting_area().get_pixel_range();
+        let (left, right) = (x.start - base_x, x.end - 1 - base_x);
+        let (top, bottom) = (y.start - base_y, y.end - 1 - base_y);
+        // the axes are mapped independently, so the other coordinate does not matter
+        let vertical = |v: &f32| {
+            let x = chart.backend_coord(&(*v, 0.0)).0 - base_x;
+            vec![(x, bottom), (x, top)]
+        };
+        let horizontal = |v: &f32| {
+            let y = chart.backend_coord(&(0.0, *v)).1 - base_y;
+            vec![(left, y), (right, y)]
+        };
+
+        // the major lines are drawn over the minor ones
+        let sets = [
+            (
+                x_minor.iter().map(vertical).collect_vec(),
+                &mesh.x_grid.minor,
+            ),
+            (
+                y_minor.iter().map(horizontal).collect_vec(),
+                &mesh.y_grid.minor,
+            ),
+            (
+                x_major.iter().map(vertical).collect_vec(),
+                &mesh.x_grid.major,
+            ),
+            (
+                y_major.iter().map(horizontal).collect_vec(),
+                &mesh.y_grid.major,
+            ),
+        ];
+        for (paths, lines) in sets.into_iter().filter(|(_, lines)| lines.visible) {
+            for path in paths {
+                self.draw_grid_lines(area, path, lines);
+            }
+        }
+    }
+
+    /// Draws the spines around the plotting area, and the tick marks of the bottom and left axes
+    /// and, when `y_ticks_r` is given, of the right axis. The ticks are the pixels of the axes'
+    /// major ticks.
+    fn draw_spines(
+        &self,
+        area: &DrawingArea<impl DrawingBackend, Shift>,
+        chart: &ChartContext<impl DrawingBackend, Cartesian2d<AxisCoord, AxisCoord>>,
+        mesh: &Mesh,
+        (x_ticks, y_ticks): (&[i32], &[i32]),
+        y_ticks_r: Option<&[i32]>,
+    ) {
+        let (base_x, base_y) = area.get_base_pixel();
+        let (x, y) = chart.plotting_area().get_pixel_range();
+        // the spines run just outside the plotting area, where plotters draws its axes
+        let (left, right) = (x.start - 1 - base_x, x.end - base_x);
+        let (top, bottom) = (y.start - 1 - base_y, y.end - base_y);
+        let spines = Spines {
+            right: mesh.spines.right || y_ticks_r.is_some(),
+            ..mesh.spines
+        };
+        // a corner is only filled when the spines on both of its sides are drawn
+        let (x0, x1) = (left + !spines.left as i32, right - !spines.right as i32);
+        let (y0, y1) = (top + !spines.top as i32, bottom - !spines.bottom as i32);
+
+        let line = |mut points: Vec<(i32, i32)>| {
+            // drawn from top left to bottom right, like plotters draws its axes
+            points.sort();
+            area.draw(&PathElement::new(points, plotters::style::BLACK))
+                .unwrap();
+        };
+        if spines.left {
+            line(vec![(left, y0), (left, y1)]);
+        }
+        if spines.right {
+            line(vec![(right, y0), (right, y1)]);
+        }
+        if spines.top {
+            line(vec![(x0, top), (x1, top)]);
+        }
+        if spines.bottom {
+            line(vec![(x0, bottom), (x1, bottom)]);
+        }
+
+        let out = match mesh.tick_direction {
+            TickDirection::Out => TICK_SIZE,
+            TickDirection::In => -TICK_SIZE,
+            TickDirection::Hidden => return,
+        };
+        for x in x_ticks.iter().map(|x| x - base_x) {
+            line(vec![(x, bottom), (x, bottom + out)]);
+        }
+        for y in y_ticks.iter().map(|y| y - base_y) {
+            line(vec![(left, y), (left - out, y)]);
+        }
+        for y in y_ticks_r.unwrap_or_default().iter().map(|y| y - base_y) {
+            line(vec![(right, y), (right + out, y)]);
+        }
+    }
+
+    /// Draws the decorations placed at `z_order` across the plotting area.
+    fn draw_decorations(
+        &self,
+        chart: &mut ChartContext<impl DrawingBackend, Cartesian2d<AxisCoord, AxisCoord>>,
+        decorations: &[Decoration],
+        z_order: ZOrder,
+    ) {
+        let Range { start: x0, end: x1 } = chart.x_range();
+        let Range { start: y0, end: y1 } = chart.y_range();
+
+        for decoration in decorations.iter().filter(|d| d.z_order == z_order) {
+            let color = convert_color(decoration.color);
+            let (points, span) = match decoration.kind {
+                DecorationKind::HLine { y } => (vec![(x0, y), (x1, y)], None),
+                DecorationKind::VLine { x } => (vec![(x, y0), (x, y1)], None),
+                DecorationKind::HSpan { y: (a, b) } => (vec![], Some([(x0, a), (x1, b)])),
+                DecorationKind::VSpan { x: (a, b) } => (vec![], Some([(a, y0), (b, y1)])),
+            };
+
+            match span {
+                Some(corners) => chart
+                    .draw_series(std::iter::once(Rectangle::new(corners, color.filled())))
+                    .unwrap(),
+                None if decoration.dashed => chart
+                    .draw_series(DashedLineSeries::new(points, 10, 8, color.into()))
+                    .unwrap(),
+                None => chart
+                    .draw_series(std::iter::once(PathElement::new(points, color)))
+                    .unwrap(),
+            };
+        }
+    }
+
+    /// Draws the annotations in pixels so that their text and markers keep a fixed size. `map`
+    /// converts their positions to the chart's data coordinates.
+    fn draw_annotations(
+        &self,
+        chart: &ChartContext<impl DrawingBackend, Cartesian2d<AxisCoord, AxisCoord>>,
+        annotations: &[Annotation],
+        map: impl Fn((f32, f32)) -> (f32, f32),
+    ) {
+        let area = chart.plotting_area();
+        let (base_x, base_y) = area.get_base_pixel();
+        let pixel = |p: (f32, f32)| {
+            let (x, y) = chart.backend_coord(&map(p));
+            (x - base_x, y - base_y)
+        };
+        let pixels = area.strip_coord_spec();
+
+        for annotation in annotations {
+            let style = annotation.style;
+            let color = convert_color(style.color);
+            let h_pos = match style.h_align {
+                HAlign::Left => HPos::Left,
+                HAlign::Center => HPos::Center,
+                HAlign::Right => HPos::Right,
+            };
+            let v_pos = match style.v_align {
+                VAlign::Top => VPos::Top,
+                VAlign::Center => VPos::Center,
+                VAlign::Bottom => VPos::Bottom,
+            };
+            let text = TextStyle::from(("sans-serif", style.font_size as f64).into_font())
+                .color(&color)
+                .pos(Pos::new(h_pos, v_pos));
+            let at = pixel(annotation.position);
+
+            match annotation.kind {
+                AnnotationKind::Text => {}
+                AnnotationKind::Arrow { target } => {
+                    let target = pixel(target);
+                    pixels
+                        .draw(&PathElement::new(vec![at, target], color))
+                        .unwrap();
+                    pixels
+                        .draw(&Polygon::new(arrow_head(at, target, 8.0), color.filled()))
+                        .unwrap();
+
+                    // the box is drawn over the start of the arrow, which begins at its center
+                    let (w, h) = pixels
+                        .estimate_text_size(&annotation.text, &text)
+                        .unwrap_or((0, 0));
+                    let (w, h) = (w as i32, h as i32);
+                    let left = match style.h_align {
+                        HAlign::Left => at.0,
+                        HAlign::Center => at.0 - w / 2,
+                        HAlign::Right => at.0 - w,
+                    };
+                    let top = match style.v_align {
+                        VAlign::Top => at.1,
+                        VAlign::Center => at.1 - h / 2,
+                        VAlign::Bottom => at.1 - h,
+                    };
+                    let pad = 4;
+                    let corners = [(left - pad, top - pad), (left + w + pad, top + h + pad)];
+                    pixels
+                        .draw(&Rectangle::new(corners, plotters::style::WHITE.filled()))
+                        .unwrap();
+                    pixels.draw(&Rectangle::new(corners, color)).unwrap();
+                }
+                AnnotationKind::Marker => {
+                    pixels.draw(&Circle::new(at, 4, color.filled())).unwrap();
+                }
+            }
+
+            let offset = match annotation.kind {
+                // keep the label clear of the marker
+                AnnotationKind::Marker => {
+                    let dx = match style.h_align {
+                        HAlign::Left => 6,
+                        HAlign::Center => 0,
+                        HAlign::Right => -6,
+                    };
+                    let dy = match style.v_align {
+                        VAlign::Top => 6,
+                        VAlign::Center => 0,
+                        VAlign::Bottom => -6,
+                    };
+                    (dx, dy)
+                }
+                _ => (0, 0),
+            };
+            pixels
+                .draw(&Text::new(
+                    annotation.text.clone(),
+                    (at.0 + offset.0, at.1 + offset.1),
+                    text,
+                ))
+                .unwrap();
+        }
+    }
+
+    fn add_box_plot(
+        &self,
+        chart: &mut ChartContext<impl DrawingBackend, Cartesian2d<AxisCoord, AxisCoord>>,
+        series: &ChartSeries,
+        config: &ChartSeriesBoxConfig,
+    ) {
+        let color = convert_color(series.style.color);
+        let half = config.width * 0.5;
+        let stats = config
+            .groups
+            .iter()
+            .enumerate()
+            .filter_map(|(i, group)| Some((i as f32, BoxStats::new(&group.samples)?)))
+            .collect::<Vec<_>>();
+
+        chart
+            .draw_series(stats.iter().map(|(x, stats)| {
+                Rectangle::new(
+                    [(x - half, stats.q1), (x + half, stats.q3)],
+                    color.mix(0.3).filled(),
+                )
+            }))
+            .unwrap();
+
+        chart
+            .draw_series(stats.iter().flat_map(|(x, stats)| {
+                let x = *x;
+                [
+                    vec![
+                        (x - half, stats.q1),
+                        (x + half, stats.q1),
+                        (x + half, stats.q3),
+                        (x - half, stats.q3),
+                        (x - half, stats.q1),
+                    ],
+                    vec![(x, stats.q3), (x, stats.whisker_hi)],
+                    vec![(x, stats.q1), (x, stats.whisker_lo)],
+                    vec![
+                        (x - half * 0.5, stats.whisker_hi),
+                        (x + half * 0.5, stats.whisker_hi),
+                    ],
+                    vec![
+                        (x - half * 0.5, stats.whisker_lo),
+                        (x + half * 0.5, stats.whisker_lo),
+                    ],
+                ]
+                .map(|points| PathElement::new(points, color))
+            }))
+            .unwrap();
+
+        chart
+            .draw_series(stats.iter().map(|(x, stats)| {
+                PathElement::new(
+                    vec![(x - half, stats.median), (x + half, stats.median)],
+                    color.stroke_width(2),
+                )
+            }))
+            .unwrap();
+
+        if config.show_outliers {
+            chart
+                .draw_series(stats.iter().flat_map(|(x, stats)| {
+                    stats
+                        .outliers
+                        .iter()
+                        .map(move |y| Circle::new((*x, *y), 2, color))
+                }))
+                .unwrap();
+        }
+    }
+
+    fn add_kde(
+        &self,
+        chart: &mut ChartContext<impl DrawingBackend, Cartesian2d<AxisCoord, AxisCoord>>,
+        series: &ChartSeries,
+        config: &ChartSeriesKdeConfig,
+    ) {
+        let style = convert_style(series.style);
+        chart
+            .draw_series(LineSeries::new(config.curve(), style))
+            .unwrap();
+
+        if config.rug {
+            // the ticks keep a fixed length at the bottom of the plotting area, so draw them in
+            // pixels
+            let area = chart.plotting_area();
+            let (base_x, _) = area.get_base_pixel();
+            let (_, h) = area.dim_in_pixel();
+            let h = h as i32;
+            let color = style.color.mix(0.6);
+            let ticks = config
+                .samples
+                .iter()
+                .filter(|x| x.is_finite())
+                .map(|x| {
+                    let (px, _) = chart.backend_coord(&(*x, 0.0));
+                    PathElement::new(vec![(px - base_x, h - 10), (px - base_x, h)], color)
+                })
+                .collect::<Vec<_>>();
+            let pixels = area.strip_coord_spec();
+            for tick in ticks {
+                pixels.draw(&tick).unwrap();
+            }
+        }
+    }
+
+    fn add_ecdf(
+        &self,
+        chart: &mut ChartContext<impl DrawingBackend, Cartesian2d<AxisCoord, AxisCoord>>,
+        series: &ChartSeries,
+        config: &ChartSeriesEcdfConfig,
+    ) {
+        let style = convert_style(series.style);
+        chart
+            .draw_series(LineSeries::new(config.curve(), style))
+            .unwrap();
+
+        let percentiles = config
+            .percentiles
+            .iter()
+            .filter_map(|p| Some((*p, config.percentile(*p)?)))
+            .collect::<Vec<_>>();
+        chart
+            .draw_series(percentiles.iter().flat_map(|(p, x)| {
+                DashedLineSeries::new(
+                    [(*x, 0.0), (*x, p / 100.0)],
+                    4,
+                    4,
+                    style.color.mix(0.7).into(),
+                )
+            }))
+            .unwrap();
+
+        // hang the labels below the top of their lines so that high percentiles stay inside
+        let text = TextStyle::from(("sans-serif", 12).into_font())
+            .color(&style.color)
+            .pos(Pos::new(HPos::Left, VPos::Top));
+        chart
+            .draw_series(percentiles.iter().map(|(p, x)| {
+                Text::new(
+                    format!(" p{} = {}", format_level(*p), format_level(*x)),
+                    (*x, p / 100.0),
+                    text.clone(),
+                )
+            }))
+            .unwrap();
+    }
+
+    fn add_heatmap(
+        &self,
+        chart: &mut ChartContext<impl DrawingBackend, Cartesian2d<AxisCoord, AxisCoord>>,
+        series: &ChartSeries,
+        config: &ChartSeriesHeatmapConfig,
+    ) {
+        let Some((scale, range)) = series.color_range() else {
+            return;
+        };
+
+        let cells = config.values.iter().enumerate().flat_map(|(row, values)| {
+            values
+                .iter()
+                .enumerate()
+                .filter(|(_, v)| v.is_finite())
+                .map(move |(col, v)| {
+                    let color = convert_color(scale.color(*v, range));
+                    Rectangle::new(config.cell(row, col), color.filled())
+                })
+        });
+        chart.draw_series(cells).unwrap();
+    }
+
+    fn add_density(
+        &self,
+        chart: &mut ChartContext<impl DrawingBackend, Cartesian2d<AxisCoord, AxisCoord>>,
+        config: &ChartSeriesDensityConfig,
+        cells: &[Bin],
+    ) {
+        let Some(range) = config.color_range(cells) else {
+            return;
+        };
+
+        let cells = cells.iter().map(|bin| {
+            let value = config.count_scale.apply(bin.count);
+            let color = convert_color(config.color_scale.color(value, range));
+            Polygon::new(bin.polygon.clone(), color.filled())
+        });
+        chart.draw_series(cells).unwrap();
+    }
+
+    fn add_candlestick(
+        &self,
+        chart: &mut ChartContext<impl DrawingBackend, Cartesian2d<AxisCoord, AxisCoord>>,
+        config: &ChartSeriesCandlestickConfig,
+    ) {
+        let half = config.width() * 0.5;
+        let up = convert_color(config.up_color).filled();
+        let down = convert_color(config.down_color).filled();
+
+        let candles = config
+            .candles
+            .iter()
+            .map(|c| {
+                // the element takes its width in pixels, so measure it at the candle's position
+                let (l, _) = chart.backend_coord(&(c.x - half, c.close));
+                let (r, _) = chart.backend_coord(&(c.x + half, c.close));
+                let width = (r - l).unsigned_abs().max(1);
+                CandleStick::new(c.x, c.open, c.high, c.low, c.close, up, down, width)
+            })
+            .collect::<Vec<_>>();
+        chart.draw_series(candles).unwrap();
+    }
+
+    fn add_quiver(
+        &self,
+        chart: &mut ChartContext<impl DrawingBackend, Cartesian2d<AxisCoord, AxisCoord>>,
+        series: &ChartSeries,
+        config: &ChartSeriesQuiverConfig,
+        arrow_size: f32,
+    ) {
+        let scale = config.scale(arrow_size);
+        let color_range = series.color_range();
+        let arrows = config
+            .vectors
+            .iter()
+            .filter(|v| v.magnitude().is_finite())
+            .map(|v| {
+                let color = match color_range {
+                    Some((color_scale, range)) => color_scale.color(v.magnitude(), range),
+                    None => series.style.color,
+                };
+                let tip = (v.x + v.u * scale, v.y + v.v * scale);
+                ((v.x, v.y), tip, convert_color(color))
+            })
+            .collect::<Vec<_>>();
+
+        chart
+            .draw_series(
+                arrows
+                    .iter()
+                    .map(|(from, to, color)| PathElement::new(vec![*from, *to], *color)),
+            )
+            .unwrap();
+
+        // the heads keep the same size regardless of the axes' scales, so draw them in pixels
+        let area = chart.plotting_area();
+        let (base_x, base_y) = area.get_base_pixel();
+        let pixel = |p: &(f32, f32)| {
+            let (x, y) = chart.backend_coord(p);
+            (x - base_x, y - base_y)
+        };
+        let heads = arrows
+            .iter()
+            .map(|(from, to, color)| {
+                Polygon::new(arrow_head(pixel(from), pixel(to), 8.0), color.filled())
+            })
+            .collect::<Vec<_>>();
+        let pixels = area.strip_coord_spec();
+        for head in heads {
+            pixels.draw(&head).unwrap();
+        }
+
+        if let Some(key) = &config.key {
+            let (w, h) = pixels.dim_in_pixel();
+            // measured from the start of the axis, where the key's length is a valid value on
+            // logarithmic axes too
+            let (start, y) = (chart.x_range().start, chart.y_range().start);
+            let (x0, _) = pixel(&(start, y));
+            let (x1, _) = pixel(&(start + key.magnitude * scale, y));
+            let len = (x1 - x0).abs();
+            let (w, h) = (w as i32, h as i32);
+            let color = convert_color(series.style.color);
+            let from = (w - 15 - len, h - 30);
+            let to = (w - 15, h - 30);
+
+            pixels
+                .draw(&PathElement::new(vec![from, to], color))
+                .unwrap();
+            pixels
+                .draw(&Polygon::new(arrow_head(from, to, 8.0), color.filled()))
+                .unwrap();
+            let style = TextStyle::from(("sans-serif", 12).into_font())
+                .color(&color)
+                .pos(Pos::new(HPos::Center, VPos::Top));
+            pixels
+                .draw(&Text::new(
+                    key.label.clone(),
+                    (to.0 - len / 2, to.1 + 6),
+                    style,
+                ))
+                .unwrap();
+        }
+    }
+
+    fn add_contour(
+        &self,
+        chart: &mut ChartContext<impl DrawingBackend, Cartesian2d<AxisCoord, AxisCoord>>,
+        series: &ChartSeries,
+        config: &ChartSeriesContourConfig,
+    ) {
+        let (Some((scale, range)), Some((lo, hi))) = (series.color_range(), config.grid().range())
+        else {
+            return;
+        };
+        let grid = config.grid();
+        let levels = config.levels();
+
+        if config.filled {
+            let inner = levels.iter().copied().filter(|l| lo < *l && *l < hi);
+            let bands = chain!([lo], inner, [hi])
+                .tuple_windows()
+                .flat_map(|band: (f32, f32)| {
+                    let color = convert_color(scale.color((band.0 + band.1) * 0.5, range));
+                    iso_band(grid, band)
+                        .into_iter()
+                        .map(move |polygon| Polygon::new(polygon, color.filled()))
+                });
+            chart.draw_series(bands).unwrap();
+        }
+
+        let line_color = |level: f32| {
+            if config.filled {
+                plotters::style::BLACK.mix(0.6)
+            } else {
+                convert_color(scale.color(level, range))
+            }
+        };
+        let lines = levels
+            .iter()
+            .map(|level| (*level, iso_lines(grid, *level)))
+            .collect::<Vec<_>>();
+
+        chart
+            .draw_series(lines.iter().flat_map(|(level, segments)| {
+                let color = line_color(*level);
+                segments
+                    .iter()
+                    .map(move |segment| PathElement::new(segment.to_vec(), color))
+            }))
+            .unwrap();
+
+        if config.labels {
+            let style = TextStyle::from(("sans-serif", 10).into_font())
+                .pos(Pos::new(HPos::Center, VPos::Center));
+            // label each level once, on the segment furthest from the labels placed so far
+            let (w, h) = (
+                (config.x_extent.1 - config.x_extent.0)
+                    .abs()
+                    .max(f32::EPSILON),
+                (config.y_extent.1 - config.y_extent.0)
+                    .abs()
+                    .max(f32::EPSILON),
+            );
+            let mut placed: Vec<(f32, f32)> = Vec::new();
+            let mut labels = Vec::new();
+            for (level, segments) in &lines {
+                let distance = |(x, y): (f32, f32)| {
+                    placed
+                        .iter()
+                        .map(|(px, py)| ((x - px) / w).hypot((y - py) / h))
+                        .fold(f32::MAX, f32::min)
+                };
+                let best = segments
+                    .iter()
+                    .map(|[(x0, y0), (x1, y1)]| ((x0 + x1) * 0.5, (y0 + y1) * 0.5))
+                    .max_by(|a, b| distance(*a).total_cmp(&distance(*b)));
+                if let Some(pos) = best {
+                    placed.push(pos);
+                    labels.push(Text::new(format_level(*level), pos, style.clone()));
+                }
+            }
+            chart.draw_series(labels).unwrap();
+        }
+    }
+
+    fn add_violin(
+        &self,
+        chart: &mut ChartContext<impl DrawingBackend, Cartesian2d<AxisCoord, AxisCoord>>,
+        series: &ChartSeries,
+        config: &ChartSeriesViolinConfig,
+    ) {
+        // number of points at which the density of each violin is evaluated
+        let resolution = 64;
+
+        let color = convert_color(series.style.color);
+        let half = config.width * 0.5;
+
+        let outlines = config
+            .groups
+            .iter()
+            .enumerate()
+            .filter_map(|(i, group)| {
+                let x = i as f32;
+                let sorted = sorted_finite(&group.samples);
+                let (lo, hi) = (*sorted.first()?, *sorted.last()?);
+                // a single value, or a group of equal ones, has no spread to estimate and is
+                // drawn as a horizontal tick across the violin's width
+                if lo == hi {
+                    return Some(vec![(x - half, lo), (x + half, lo)]);
+                }
+                let bandwidth = scott_bandwidth(&sorted)
+                    .filter(|bw| *bw > 0.0)
+                    .unwrap_or((hi - lo) / resolution as f32);
+
+                let profile = linspace(lo, hi, resolution)
+                    .map(|y| (y, gaussian_kde(&sorted, bandwidth, y)))
+                    .collect::<Vec<_>>();
+                let max_density = profile.iter().map(|(_, d)| *d).fold(0.0, f32::max);
+
+                let right = profile
+                    .iter()
+                    .map(|(y, d)| (x + d / max_density * half, *y));
+                let left = profile
+                    .iter()
+                    .rev()
+                    .map(|(y, d)| (x - d / max_density * half, *y));
+                Some(right.chain(left).collect::<Vec<_>>())
+            })
+            .collect::<Vec<_>>();
+
+        chart
+            .draw_series(
+                outlines
+                    .iter()
+                    .map(|points| Polygon::new(points.clone(), color.mix(0.3).filled())),
+            )
+            .unwrap();
+
+        chart
+            .draw_series(outlines.iter().map(|points| {
+                let closed = chain!(points, points.first()).copied().collect::<Vec<_>>();
+                PathElement::new(closed, color)
+            }))
+            .unwrap();
+
+        if config.show_box {
+            let stats = config
+                .groups
+                .iter()
+                .enumerate()
+                .filter_map(|(i, group)| Some((i as f32, BoxStats::new(&group.samples)?)))
+                .collect::<Vec<_>>();
+
+            chart
+                .draw_series(stats.iter().map(|(x, stats)| {
+                    PathElement::new(vec![(*x, stats.q1), (*x, stats.q3)], color.stroke_width(4))
+                }))
+                .unwrap();
+            chart
+                .draw_series(stats.iter().map(|(x, stats)| {
+                    Circle::new((*x, stats.median), 2, plotters::style::WHITE.filled())
+                }))
+                .unwrap();
+        }
+    }
+
+    fn add_error_bars(
+        &self,
+        chart: &mut ChartContext<impl DrawingBackend, Cartesian2d<AxisCoord, AxisCoord>>,
+        series: &ChartSeries,
+    ) {
+        // width of the caps at the ends of each whisker, in pixels
+        let cap = 6;
+        let style = convert_style(series.style);
+
+        let x_err = izip!(&series.data, &series.x_err).map(|(&(x, y), err)| {
+            let (min, max) = err.bound(x).as_tuple();
+            ErrorBar::new_horizontal(y, min, x, max, style, cap)
+        });
+        chart.draw_series(x_err).unwrap();
+
+        let y_err = izip!(&series.data, &series.y_err).map(|(&(x, y), err)| {
+            let (min, max) = err.bound(y).as_tuple();
+            ErrorBar::new_vertical(x, min, y, max, style, cap)
+        });
+        chart.draw_series(y_err).unwrap();
+    }
+
     fn process_chart(&self, area: &DrawingArea<impl DrawingBackend, Shift>, chart_cmd: &Chart) {
-        let (x_bounds, y_bounds, y_bounds_r) = chart_cmd.bounds();
-        let x_range = x_bounds.unwrap_or(DataBound::zero()).as_range();
-        let y_range = y_bounds.unwrap_or(DataBound::zero()).as_range();
-        let y_range_r = y_bounds_r.unwrap_or(DataBound::zero()).as_range();
+        let series = chain!(&chart_cmd.series_l, &chart_cmd.series_r);
+        let cells = series.clone().map(density_cells).collect_vec();
+        let colorbars = izip!(series, &cells)
+            .filter_map(|(series, cells)| match &series.chart_series_type {
+                // the counts are taken from the bins the series is drawn with
+                CST::Density(d) => Some((&d.color_scale, d.color_range(cells)?)),
+                _ => series.color_range(),
+            })
+            .filter_map(|(scale, range)| Some((scale.colormap, range, scale.colorbar.as_ref()?)))
+            .collect::<Vec<_>>();
+        self.with_colorbars(area, colorbars, |area| {
+            self.with_legend(area, chart_cmd, |area| {
+                self.draw_chart(area, chart_cmd, &cells)
+            })
+        });
+    }
 
-        let m = 40;
+    /// Reserves space for a legend placed outside of the plotting area, to the right of or
+    /// below the area, draws the chart in the rest of it with `draw`, and then draws the legend
+    /// beside the chart. Returns the vertical pixel range of the chart's plotting area.
+    fn with_legend<DB: DrawingBackend>(
+        &self,
+        area: &DrawingArea<DB, Shift>,
+        chart_cmd: &Chart,
+        draw: impl FnOnce(&DrawingArea<DB, Shift>) -> Range<i32>,
+    ) -> Range<i32> {
+        let position = chart_cmd.legend.position;
+        let layout = match position.is_outside() {
+            true => LegendLayout::new(area, chart_cmd),
+            false => None,
+        };
+        let Some(layout) = layout else {
+            return draw(area);
+        };
 
-        let right_margin = if chart_cmd.series_r.is_empty() { 0 } else { m };
+        let (w, h) = layout.size();
+        let (area_w, area_h) = area.dim_in_pixel();
+        if position == LegendPosition::OutsideRight {
+            let (main, side) = area.split_horizontally(area_w.saturating_sub(w + LEGEND_PAD));
+            let plot_y = draw(&main);
+            // centered on the plotting area
+            let (_, side_y) = side.get_pixel_range();
+            let y = (plot_y.start + plot_y.end - h as i32) / 2 - side_y.start;
+            layout.draw(&side, (0, y.max(0)));
+            plot_y
+        } else {
+            let (main, below) = area.split_vertically(area_h.saturating_sub(h + LEGEND_PAD));
+            let plot_y = draw(&main);
+            let x = (area_w as i32 - w as i32) / 2;
+            layout.draw(&below, (x.max(0), 0));
+            plot_y
+        }
+    }
 
+    /// Draws the legend inside the plotting area of a chart drawn on `area`, unless it is
+    /// placed outside of it. `points` are the pixels of the series' drawings, see
+    /// [`ChartSeries::legend_avoid_points`], which a legend placed at [`LegendPosition::Best`]
+    /// avoids.
+    fn draw_legend(
+        &self,
+        area: &DrawingArea<impl DrawingBackend, Shift>,
+        chart: &ChartContext<impl DrawingBackend, Cartesian2d<AxisCoord, AxisCoord>>,
+        chart_cmd: &Chart,
+        points: &[(i32, i32)],
+    ) {
+        let position = chart_cmd.legend.position;
+        if position.is_outside() {
+            return;
+        }
+        let Some(layout) = LegendLayout::new(area, chart_cmd) else {
+            return;
+        };
+
+        let plot = chart.plotting_area().get_pixel_range();
+        let position = match position {
+            LegendPosition::Best => layout.best(plot.clone(), points),
+            position => position,
+        };
+        let (x, y) = layout.corner(position, plot);
+        let (base_x, base_y) = area.get_base_pixel();
+        layout.draw(area, (x - base_x, y - base_y));
+    }
+
+    /// Reserves space for the colorbars to the right of the area, draws the chart in the rest of
+    /// it with `draw`, which returns the vertical pixel range of its plotting area, and then
+    /// draws the colorbars aligned with that range.
+    fn with_colorbars<DB: DrawingBackend>(
+        &self,
+        area: &DrawingArea<DB, Shift>,
+        colorbars: Vec<(Colormap, (f32, f32), &Colorbar)>,
+        draw: impl FnOnce(&DrawingArea<DB, Shift>) -> Range<i32>,
+    ) {
+        if colorbars.is_empty() {
+            draw(area);
+            return;
+        }
+
+        let (w, _) = area.dim_in_pixel();
+        let (main, bars) =
+            area.split_horizontally(w.saturating_sub(COLORBAR_WIDTH * colorbars.len() as u32));
+        let plot_y = draw(&main);
+
+        let bars = bars.split_evenly((1, colorbars.len()));
+        for (bar, (colormap, range, colorbar)) in izip!(bars, colorbars) {
+            self.draw_colorbar(&bar, colormap, range, colorbar, plot_y.clone());
+        }
+    }
+
+    /// Draws a colorbar whose scale is vertically aligned with `plot_y`, the pixel range of the
+    /// chart's plotting area.
+    fn draw_colorbar(
+        &self,
+        area: &DrawingArea<impl DrawingBackend, Shift>,
+        colormap: Colormap,
+        (lo, hi): (f32, f32),
+        colorbar: &Colorbar,
+        plot_y: Range<i32>,
+    ) {
+        // number of bands the gradient is drawn with
+        let steps = 64;
+        // a constant value still gets a scale around it
+        let (lo, hi) = Axis::default().range(Some(DataBound::from((lo, hi))), None);
+
+        let (_, area_y) = area.get_pixel_range();
         let mut chart = ChartBuilder::on(area)
-            .caption(&chart_cmd.title, ("sans-serif", 20))
-            .margin(0)
-            .margin_right(m - right_margin)
-            .x_label_area_size(m)
-            .y_label_area_size(m)
-            .right_y_label_area_size(right_margin)
-            .build_cartesian_2d(x_range.clone(), y_range)
-            .unwrap()
-            .set_secondary_coord(x_range, y_range_r);
+            .margin_top((plot_y.start - area_y.start).max(0))
+            .margin_bottom((area_y.end - plot_y.end).max(0))
+            .margin_left(10)
+            .right_y_label_area_size(COLORBAR_WIDTH as i32 - 30)
+            .build_cartesian_2d(0f32..1f32, lo..hi)
+            .unwrap();
+
+        let step = (hi - lo) / steps as f32;
+        chart
+            .draw_series((0..steps).map(|i| {
+                let y = lo + step * i as f32;
+                let color = convert_color(colormap.sample_range(y + step * 0.5, (lo, hi)));
+                Rectangle::new([(0.0, y), (1.0, y + step)], color.filled())
+            }))
+            .unwrap();
+        chart
+            .draw_series(std::iter::once(Rectangle::new(
+                [(0.0, lo), (1.0, hi)],
+                plotters::style::BLACK,
+            )))
+            .unwrap();
 
         chart
             .configure_mesh()
-            .x_max_light_lines(1)
-            .y_max_light_lines(1)
-            .y_desc(&chart_cmd.y_label_l)
+            .disable_mesh()
+            .disable_x_axis()
+            // the default font size is relative to the narrow plotting area, so set it explicitly
+            .y_label_style(("sans-serif", 12))
+            .axis_desc_style(("sans-serif", 12))
+            .y_desc(&colorbar.label)
             .draw()
             .unwrap();
+    }
+
+    /// Draws the chart's axes and series, and returns the vertical pixel range of its plotting
+    /// area. `cells` holds the bins of each of the chart's series, left then right, see
+    /// [`density_cells`].
+    fn draw_chart(
+        &self,
+        area: &DrawingArea<impl DrawingBackend, Shift>,
+        chart_cmd: &Chart,
+        cells: &[Vec<Bin>],
+    ) -> Range<i32> {
+        if let Some(polar) = &chart_cmd.polar {
+            return self.draw_polar_chart(area, chart_cmd, polar);
+        }
 
-        for series in chart_cmd.series_l.clone() {
-            self.add_series(&mut chart, &series);
+        let (x_range, y_range, y_range_r) = chart_cmd.axis_ranges();
+        let (x_axis, y_axis_l, y_axis_r) =
+            (&chart_cmd.x_axis, &chart_cmd.y_axis_l, &chart_cmd.y_axis_r);
+        let x_coord = match x_axis.time {
+            // leaves about 100 pixels for each calendar label
+            Some(time) => AxisCoord::time(time, x_range, area.dim_in_pixel().0 as usize / 100),
+            None => AxisCoord::new(x_axis.scale, x_range),
         }
+        .with_ticks(x_axis.ticks.clone());
+        let y_coord = AxisCoord::new(y_axis_l.scale, y_range).with_ticks(y_axis_l.ticks.clone());
+        let y_coord_r =
+            AxisCoord::new(y_axis_r.scale, y_range_r).with_ticks(y_axis_r.ticks.clone());
+
+        let m = 40;
+
+        // the y label areas fit the longest tick label, and the axis description beside it
+        let label_area = |coord: &AxisCoord, axis: &Axis, desc: &str| {
+            let chars = coord.label_chars(axis.ticks.major.unwrap_or(DEFAULT_LABELS)) as u32;
+            let desc = if desc.is_empty() { 0 } else { 16 };
+            (chars * CHAR_WIDTH + 12 + desc).max(m as u32)
+        };
+        let left_area = label_area(&y_coord, y_axis_l, &chart_cmd.y_label_l);
+        let right_margin = if chart_cmd.series_r.is_empty() {
+            0
+        } else {
+            label_area(&y_coord_r, y_axis_r, &chart_cmd.y_label_r) as i32
+        };
+
+        // category labels are turned vertical when they are wider than their slots
+        let categories = chart_cmd.x_categories();
+        let (w, h) = area.dim_in_pixel();
+        let longest = categories
+            .iter()
+            .map(|c| c.chars().count())
+            .max()
+            .unwrap_or(0) as u32;
+        let slot = w.saturating_sub(2 * m as u32) / categories.len().max(1) as u32;
+        let rotate_labels = longest * CHAR_WIDTH > slot;
+        let x_label_area = if rotate_labels {
+            (longest * CHAR_WIDTH + 10).clamp(m as u32, h / 3)
+        } else {
+            m as u32
+        };
+
+        let mut chart = ChartBuilder::on(area)
+            .caption(&chart_cmd.title, ("sans-serif", 20))
+            .margin(0)
+            .margin_right((m - right_margin).max(0))
+            .x_label_area_size(x_label_area)
+            .y_label_area_size(left_area)
+            .right_y_label_area_size(right_margin)
+            .build_cartesian_2d(x_coord.clone(), y_coord.clone())
+            .unwrap()
+            .set_secondary_coord(x_coord.clone(), y_coord_r.clone());
 
-        if chart_cmd.series_r.is_empty() {
-            let mut series_labels = chart.configure_series_labels();
-            self.configure_series_labels(&mut series_labels, chart_cmd);
-            series_labels.draw().unwrap();
+        let category_formatter = |x: &f32| {
+            let i = x.round();
+            if (x - i).abs() < 1e-3 && i >= 0.0 {
+                categories.get(i as usize).unwrap_or(&"").to_string()
+            } else {
+                "".to_string()
+            }
+        };
+
+        let x_labels = if categories.is_empty() {
+            x_axis.ticks.major.unwrap_or(DEFAULT_LABELS)
         } else {
+            // with one unit per category, this limit places a label on every category
+            categories.len()
+        };
+        let y_labels = y_axis_l.ticks.major.unwrap_or(DEFAULT_LABELS);
+
+        let mut mesh = chart.configure_mesh();
+        // plotters only draws the labels, and the grid, spines and tick marks are drawn in the
+        // chart's mesh style
+        mesh.disable_mesh()
+            .axis_style(TRANSPARENT)
+            .x_labels(x_labels)
+            .y_labels(y_labels)
+            .y_desc(&chart_cmd.y_label_l);
+        if !categories.is_empty() {
+            // plotters centers the labels on their ticks, so rotated labels are drawn below
+            if rotate_labels {
+                mesh.x_label_formatter(&|_| "".to_string());
+            } else {
+                mesh.x_label_formatter(&category_formatter);
+            }
+        }
+        mesh.draw().unwrap();
+        let x_ticks = tick_values(&x_coord, x_labels, x_axis.ticks.minor);
+        let y_ticks = tick_values(&y_coord, y_labels, y_axis_l.ticks.minor);
+        self.draw_grid(area, &chart, &chart_cmd.mesh, &x_ticks, &y_ticks);
+        if rotate_labels {
+            self.draw_rotated_labels(area, &chart, &categories);
+        }
+
+        self.draw_decorations(&mut chart, &chart_cmd.decorations, ZOrder::Below);
+
+        let (cells_l, cells_r) = cells.split_at(chart_cmd.series_l.len());
+        let arrow_size = chart_cmd.arrow_size(false);
+        for (series, cells) in izip!(&chart_cmd.series_l, cells_l) {
+            self.add_series(&mut chart, series, cells, arrow_size);
+        }
+        let mut points = chart_cmd
+            .series_l
+            .iter()
+            .flat_map(ChartSeries::legend_avoid_points)
+            .map(|p| chart.backend_coord(&p))
+            .collect_vec();
+
+        let mut y_ticks_r = None;
+        if !chart_cmd.series_r.is_empty() {
+            let y_labels_r = y_axis_r.ticks.major.unwrap_or(DEFAULT_LABELS);
             chart
                 .configure_secondary_axes()
+                .axis_style(TRANSPARENT)
+                .y_labels(y_labels_r)
                 .y_desc(&chart_cmd.y_label_r)
                 .draw()
                 .unwrap();
 
-            for series in chart_cmd.series_r.clone() {
-                self.add_series(&mut chart, &series);
+            // the secondary series are drawn in their own context, so that the helpers map their
+            // data through the secondary axis
+            let plotting_area = chart.plotting_area().strip_coord_spec();
+            let mut chart_r = ChartBuilder::on(&plotting_area)
+                .build_cartesian_2d(x_coord, y_coord_r.clone())
+                .unwrap();
+            let arrow_size = chart_cmd.arrow_size(true);
+            for (series, cells) in izip!(&chart_cmd.series_r, cells_r) {
+                self.add_series(&mut chart_r, series, cells, arrow_size);
             }
+            points.extend(
+                chart_cmd
+                    .series_r
+                    .iter()
+                    .flat_map(ChartSeries::legend_avoid_points)
+                    .map(|p| chart_r.backend_coord(&p)),
+            );
+            // mapped like plotters maps the secondary axis' labels
+            let secondary = chart.borrow_secondary();
+            let ticks = y_coord_r.key_points(BoldPoints(y_labels_r));
+            let pixels = ticks.iter().map(|y| secondary.backend_coord(&(0.0, *y)).1);
+            y_ticks_r = Some(pixels.collect_vec());
+        }
 
-            let mut series_labels = chart.configure_series_labels();
-            self.configure_series_labels(&mut series_labels, chart_cmd);
-            series_labels.draw().unwrap();
+        self.draw_decorations(&mut chart, &chart_cmd.decorations, ZOrder::Above);
+        let x_pixels = x_ticks.0.iter().map(|x| chart.backend_coord(&(*x, 0.0)).0);
+        let y_pixels = y_ticks.0.iter().map(|y| chart.backend_coord(&(0.0, *y)).1);
+        let ticks = (&x_pixels.collect_vec()[..], &y_pixels.collect_vec()[..]);
+        self.draw_spines(area, &chart, &chart_cmd.mesh, ticks, y_ticks_r.as_deref());
+        self.draw_annotations(&chart, &chart_cmd.annotations, |p| p);
+        self.draw_legend(area, &chart, chart_cmd, &points);
+
+        chart.plotting_area().get_pixel_range().1
+    }
+
+    /// Draws the categories of the x axis below its ticks, reading from top to bottom.
+    fn draw_rotated_labels(
+        &self,
+        area: &DrawingArea<impl DrawingBackend, Shift>,
+        chart: &ChartContext<impl DrawingBackend, Cartesian2d<AxisCoord, AxisCoord>>,
+        categories: &[&str],
+    ) {
+        let (base_x, base_y) = area.get_base_pixel();
+        let bottom = chart.plotting_area().get_pixel_range().1.end;
+        let style = TextStyle::from(("sans-serif", 12).into_font())
+            .transform(FontTransform::Rotate90)
+            .pos(Pos::new(HPos::Left, VPos::Center));
+        for (i, category) in categories.iter().enumerate() {
+            let (x, _) = chart.backend_coord(&(i as f32, 0.0));
+            let at = (x - base_x, bottom - base_y + 8);
+            area.draw(&Text::new(category.to_string(), at, &style))
+                .unwrap();
         }
     }
 
+    /// Draws the chart's grid and series in polar coordinates, and returns the vertical pixel
+    /// range of its plotting area.
+    fn draw_polar_chart(
+        &self,
+        area: &DrawingArea<impl DrawingBackend, Shift>,
+        chart_cmd: &Chart,
+        polar: &Polar,
+    ) -> Range<i32> {
+        let r_max = chart_cmd.r_max().filter(|r| *r > 0.0).unwrap_or(1.0);
+        let step = nice_step(r_max / polar.radial_lines.max(1) as f32);
+        let r_max = (r_max / step).ceil() * step;
+
+        let area = if chart_cmd.title.is_empty() {
+            area.clone()
+        } else {
+            area.titled(&chart_cmd.title, ("sans-serif", 20)).unwrap()
+        };
+        // keep the chart square so that circles stay round
+        let (w, h) = area.dim_in_pixel();
+        let size = w.min(h) as i32;
+        let (dx, dy) = ((w as i32 - size) / 2, (h as i32 - size) / 2);
+        let area = area.margin(dy, dy, dx, dx);
+
+        // leave room around the outer circle for the angular labels
+        let extent = r_max * 1.2;
+        let mut chart = ChartBuilder::on(&area)
+            .build_cartesian_2d(
+                AxisCoord::linear((-extent, extent)),
+                AxisCoord::linear((-extent, extent)),
+            )
+            .unwrap();
+
+        // the circles are drawn in the style of the y axis' grid, which stands for the radial
+        // axis, and the spokes in the style of the x axis' grid
+        let (circles, spokes) = (&chart_cmd.mesh.y_grid.major, &chart_cmd.mesh.x_grid.major);
+        let circle = |r: f32| {
+            linspace(0.0, std::f32::consts::TAU, 121)
+                .map(|a| (r * a.cos(), r * a.sin()))
+                .collect_vec()
+        };
+        let radii = (1..)
+            .map(|i| step * i as f32)
+            .take_while(|r| *r <= r_max * (1.0 + 1e-4))
+            .collect_vec();
+        let spoke = |i: usize| std::f32::consts::TAU * i as f32 / polar.angular_lines.max(1) as f32;
+
+        let pixels = chart.plotting_area().strip_coord_spec();
+        let (base_x, base_y) = pixels.get_base_pixel();
+        let pixel = |p: &(f32, f32)| {
+            let (x, y) = chart.backend_coord(p);
+            (x - base_x, y - base_y)
+        };
+        if circles.visible {
+            for r in &radii {
+                let path = circle(*r).iter().map(pixel).collect();
+                self.draw_grid_lines(&pixels, path, circles);
+            }
+        }
+        if spokes.visible {
+            for i in 0..polar.angular_lines {
+                let a = spoke(i);
+                let path = [(0.0, 0.0), (r_max * a.cos(), r_max * a.sin())];
+                self.draw_grid_lines(&pixels, path.iter().map(pixel).collect(), spokes);
+            }
+        }
+        chart
+            .draw_series(std::iter::once(PathElement::new(
+                circle(r_max),
+                plotters::style::BLACK.to_rgba(),
+            )))
+            .unwrap();
+
+        let label = TextStyle::from(("sans-serif", 12).into_font())
+            .pos(Pos::new(HPos::Center, VPos::Center));
+        let r_label = r_max * 1.1;
+        chart
+            .draw_series((0..polar.angular_lines).map(|i| {
+                let a = spoke(i);
+                Text::new(
+                    polar.angle_label(i),
+                    (r_label * a.cos(), r_label * a.sin()),
+                    label.clone(),
+                )
+            }))
+            .unwrap();
+
+        // the radial labels sit between the first two spokes
+        let a = spoke(1) * 0.5;
+        let radial_color = plotters::style::BLACK.mix(0.6);
+        let radial_label = label.color(&radial_color);
+        chart
+            .draw_series(radii.iter().map(|r| {
+                Text::new(
+                    format_level(*r),
+                    (r * a.cos(), r * a.sin()),
+                    radial_label.clone(),
+                )
+            }))
+            .unwrap();
+
+        let mut points = vec![];
+        let series = chain!(&chart_cmd.series_l, &chart_cmd.series_r)
+            .filter(|series| series.chart_series_type.supports_polar());
+        for series in series {
+            let mut series = series.clone();
+            series.data = series.data.iter().map(|p| polar.to_cartesian(*p)).collect();
+            let avoid = series.legend_avoid_points();
+            points.extend(avoid.iter().map(|p| chart.backend_coord(p)));
+            series.x_err.clear();
+            series.y_err.clear();
+
+            if let CST::Line(line) = &mut series.chart_series_type {
+                if line.filled {
+                    let color = convert_color(series.style.color).mix(0.3).filled();
+                    chart
+                        .draw_series(std::iter::once(Polygon::new(series.data.clone(), color)))
+                        .unwrap();
+                    line.filled = false;
+                }
+            }
+            self.add_series(&mut chart, &series, &[], 1.0);
+        }
+
+        self.draw_annotations(&chart, &chart_cmd.annotations, |p| polar.to_cartesian(p));
+        self.draw_legend(&area, &chart, chart_cmd, &points);
+
+        chart.plotting_area().get_pixel_range().1
+    }
+
+    fn process_chart3d(&self, area: &DrawingArea<impl DrawingBackend, Shift>, chart_cmd: &Chart3D) {
+        let colorbars = chart_cmd
+            .series
+            .iter()
+            .filter_map(|series| series.color_range())
+            .filter_map(|(scale, range)| Some((scale.colormap, range, scale.colorbar.as_ref()?)))
+            .collect::<Vec<_>>();
+        self.with_colorbars(area, colorbars, |area| self.draw_chart3d(area, chart_cmd));
+    }
+
+    /// Draws the 3-D chart's axes and series, and returns the vertical pixel range of its drawing
+    /// area. Plotters' vertical axis is its y axis, so the chart's y and z are swapped when
+    /// drawing.
+    fn draw_chart3d(
+        &self,
+        area: &DrawingArea<impl DrawingBackend, Shift>,
+        chart_cmd: &Chart3D,
+    ) -> Range<i32> {
+        let (x_bounds, y_bounds, z_bounds) =
+            chart_cmd
+                .bounds()
+                .unwrap_or((DataBound::zero(), DataBound::zero(), DataBound::zero()));
+
+        let mut chart = ChartBuilder::on(area)
+            .caption(&chart_cmd.title, ("sans-serif", 20))
+            .margin(10)
+            .build_cartesian_3d(
+                x_bounds.as_range(),
+                z_bounds.as_range(),
+                y_bounds.as_range(),
+            )
+            .unwrap();
+
+        let camera = chart_cmd.camera;
+        chart.with_projection(|mut p| {
+            p.yaw = camera.yaw as f64;
+            p.pitch = camera.pitch as f64;
+            p.scale = camera.scale as f64;
+            p.into_matrix()
+        });
+
+        chart
+            .configure_axes()
+            .label_style(("sans-serif", 12))
+            .light_grid_style(plotters::style::BLACK.mix(0.1))
+            .max_light_lines(1)
+            .draw()
+            .unwrap();
+
+        // the axes have no descriptions, so label the middle of the edges along each axis
+        let (x0, x1) = x_bounds.as_tuple();
+        let (y0, y1) = y_bounds.as_tuple();
+        let (z0, z1) = z_bounds.as_tuple();
+        let (xm, ym, zm) = ((x0 + x1) * 0.5, (y0 + y1) * 0.5, (z0 + z1) * 0.5);
+        let desc = TextStyle::from(("sans-serif", 14).into_font())
+            .pos(Pos::new(HPos::Center, VPos::Center));
+        let descs = [
+            (&chart_cmd.x_label, (xm, z0, y1 + (y1 - y0) * 0.25)),
+            (&chart_cmd.y_label, (x1 + (x1 - x0) * 0.25, z0, ym)),
+            (
+                &chart_cmd.z_label,
+                (x0 - (x1 - x0) * 0.15, zm, y1 + (y1 - y0) * 0.15),
+            ),
+        ];
+        chart
+            .draw_series(
+                descs
+                    .into_iter()
+                    .filter(|(label, _)| !label.is_empty())
+                    .map(|(label, pos)| Text::new(label.clone(), pos, desc.clone())),
+            )
+            .unwrap();
+
+        for series in &chart_cmd.series {
+            let color = convert_color(series.style.color);
+            let points = series.data.iter().map(|(x, y, z)| (*x, *z, *y));
+            let anno = match &series.chart_series_type {
+                Chart3DSeriesType::Line => chart.draw_series(LineSeries::new(points, color)),
+                Chart3DSeriesType::Scatter => {
+                    chart.draw_series(points.map(|p| Circle::new(p, 3, color.filled())))
+                }
+                Chart3DSeriesType::Surface(surface) => {
+                    let Some((scale, range)) = series.color_range() else {
+                        continue;
+                    };
+                    let grid = surface.grid();
+                    let (rows, cols) = (grid.rows(), grid.cols());
+                    let (gx0, gx1) = grid.x_extent;
+                    let (gy0, gy1) = grid.y_extent;
+                    let dx = (gx1 - gx0) / cols.saturating_sub(1).max(1) as f32;
+                    let dy = (gy1 - gy0) / rows.saturating_sub(1).max(1) as f32;
+                    let node = |row: usize, col: usize| {
+                        (
+                            gx0 + dx * col as f32,
+                            grid.values[row][col],
+                            gy0 + dy * row as f32,
+                        )
+                    };
+
+                    let coord = chart.as_coord_spec();
+                    let mut cells = (0..rows.saturating_sub(1))
+                        .flat_map(|row| (0..cols.saturating_sub(1)).map(move |col| (row, col)))
+                        .map(|(row, col)| {
+                            [
+                                node(row, col),
+                                node(row, col + 1),
+                                node(row + 1, col + 1),
+                                node(row + 1, col),
+                            ]
+                        })
+                        .filter(|corners| corners.iter().all(|(_, z, _)| z.is_finite()))
+                        .map(|corners| {
+                            let (x, z, y) = corners.iter().fold((0.0, 0.0, 0.0), |acc, c| {
+                                (acc.0 + c.0 * 0.25, acc.1 + c.1 * 0.25, acc.2 + c.2 * 0.25)
+                            });
+                            (coord.projected_depth(&x, &z, &y), z, corners)
+                        })
+                        .collect_vec();
+                    // painter's algorithm: draw the furthest cells first
+                    cells.sort_by_key(|(depth, _, _)| std::cmp::Reverse(*depth));
+
+                    chart.draw_series(cells.into_iter().map(|(_, z, corners)| {
+                        let color = convert_color(scale.color(z, range));
+                        Polygon::new(corners.to_vec(), color.filled())
+                    }))
+                }
+            };
+            let s = anno.unwrap();
+            if !series.name.is_empty() {
+                s.label(&series.name)
+                    .legend(move |(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], color));
+            }
+        }
+
+        if chart_cmd
+            .series
+            .iter()
+            .any(|series| !series.name.is_empty())
+        {
+            chart
+                .configure_series_labels()
+                .position(plotters::chart::SeriesLabelPosition::UpperLeft)
+                .background_style(plotters::style::WHITE.mix(0.8))
+                .border_style(plotters::style::BLACK)
+                .draw()
+                .unwrap();
+        }
+
+        chart.plotting_area().get_pixel_range().1
+    }
+
     fn process_image(
         &self,
         area: &DrawingArea<impl DrawingBackend, Shift>,
@@ -212,6 +1939,97 @@ impl PlottersProcessor {
         chart.draw_series(std::iter::once(elem)).unwrap();
     }
 
+    fn process_pie(&self, area: &DrawingArea<impl DrawingBackend, Shift>, pie: &Pie) {
+        let area = if pie.title.is_empty() {
+            area.clone()
+        } else {
+            area.titled(&pie.title, ("sans-serif", 20)).unwrap()
+        };
+
+        let slices = pie.visible_slices().collect::<Vec<_>>();
+        if slices.is_empty() {
+            return;
+        }
+
+        let label_style = TextStyle::from(("sans-serif", 12).into_font());
+        let area = if pie.legend {
+            let (w, _) = area.dim_in_pixel();
+            let text_w = slices
+                .iter()
+                .filter_map(|slice| area.estimate_text_size(&slice.label, &label_style).ok())
+                .map(|(w, _)| w)
+                .max()
+                .unwrap_or(0);
+            let (pie_area, legend_area) = area.split_horizontally(w.saturating_sub(text_w + 40));
+            self.draw_pie_legend(&legend_area, &slices, &label_style);
+            pie_area
+        } else {
+            area
+        };
+
+        let (w, h) = area.dim_in_pixel();
+        let (x0, y0) = area.get_base_pixel();
+        // the pie element draws in absolute pixel coordinates
+        let center = (x0 + w as i32 / 2, y0 + h as i32 / 2);
+        // leave room around the pie for the labels drawn outside of it
+        let fill = if pie.labels { 0.7 } else { 0.9 };
+        let radius = w.min(h) as f64 * 0.5 * fill;
+
+        let sizes = slices.iter().map(|s| s.value as f64).collect::<Vec<_>>();
+        let colors = slices
+            .iter()
+            .map(|s| {
+                let RGBAColor(r, g, b, _) = convert_color(s.color);
+                RGBColor(r, g, b)
+            })
+            .collect::<Vec<_>>();
+        let labels = slices
+            .iter()
+            .map(|s| if pie.labels { s.label.as_str() } else { "" })
+            .collect::<Vec<_>>();
+
+        let mut elem = plotters::element::Pie::new(&center, &radius, &sizes, &colors, &labels);
+        elem.start_angle(pie.start_angle as f64);
+        elem.label_style(label_style);
+        elem.donut_hole(radius * pie.donut_hole as f64);
+        if pie.percentages {
+            elem.percentages(
+                ("sans-serif", 12)
+                    .into_font()
+                    .color(&plotters::style::WHITE),
+            );
+        }
+        area.draw(&elem).unwrap();
+    }
+
+    fn draw_pie_legend(
+        &self,
+        area: &DrawingArea<impl DrawingBackend, Shift>,
+        slices: &[&PieSlice],
+        label_style: &TextStyle,
+    ) {
+        // height of each legend entry and size of its color swatch, in pixels
+        let (row, swatch) = (20, 12);
+
+        let (_, h) = area.dim_in_pixel();
+        let top = (h as i32 - row * slices.len() as i32).max(0) / 2;
+        for (i, slice) in slices.iter().enumerate() {
+            let y = top + row * i as i32;
+            let color = convert_color(slice.color);
+            area.draw(&Rectangle::new(
+                [(0, y), (swatch, y + swatch)],
+                color.filled(),
+            ))
+            .unwrap();
+            area.draw(&Text::new(
+                slice.label.as_str(),
+                (swatch + 8, y),
+                label_style.clone(),
+            ))
+            .unwrap();
+        }
+    }
+
     fn process_layout(&self, area: &DrawingArea<impl DrawingBackend, Shift>, layout: &Layout) {
         use crate::draw_command::layout::Layout;
         match layout {
@@ -248,8 +2066,10 @@ impl PlottersProcessor {
         match cmd {
             DrawComand::Blank => {}
             DrawComand::Chart(chart) => self.process_chart(area, chart),
+            DrawComand::Chart3D(chart) => self.process_chart3d(area, chart),
             DrawComand::Image(image) => self.process_image(area, &image),
             DrawComand::Layout(layout) => self.process_layout(area, layout),
+            DrawComand::Pie(pie) => self.process_pie(area, pie),
         }
     }
 }
@@ -263,3 +2083,34 @@ impl CmdProcessor for PlottersProcessor {
         root.present().unwrap();
     }
 }
+
+#[cfg(all(test, feature = "builder"))]
+mod tests {
+    use super::*;
+    use crate::draw_command::{chart::axis::AxisScale, plot_chart};
+
+    /// Draws a command onto an in-memory bitmap.
+    fn render(cmd: &DrawComand) {
+        let (w, h) = (320, 240);
+        let mut buffer = vec![0; (w * h * 3) as usize];
+        let processor = PlottersProcessor {
+            path: PathBuf::new(),
+            w,
+            h,
+        };
+        let root = BitMapBackend::with_buffer(&mut buffer, (w, h)).into_drawing_area();
+        processor.process_command(&root, cmd);
+        root.present().unwrap();
+    }
+
+    #[test]
+    fn symlog_axes_render_with_a_zero_threshold() {
+        let data = (-100..=100)
+            .map(|i| (i as f32, i as f32))
+            .collect::<Vec<_>>();
+        render(&plot_chart(|b| {
+            b.add_series_l_with(|b| b.data(data))
+                .y_scale_l(AxisScale::SymLog { threshold: 0.0 })
+        }));
+    }
+}
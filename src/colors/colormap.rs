@@ -0,0 +1,134 @@
+use rgb::RGBA;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+const VIRIDIS: [[u8; 3]; 11] = [
+    [0x44, 0x01, 0x54],
+    [0x48, 0x24, 0x75],
+    [0x41, 0x44, 0x87],
+    [0x35, 0x5f, 0x8d],
+    [0x2a, 0x78, 0x8e],
+    [0x21, 0x91, 0x8c],
+    [0x22, 0xa8, 0x84],
+    [0x44, 0xbf, 0x70],
+    [0x7a, 0xd1, 0x51],
+    [0xbd, 0xdf, 0x26],
+    [0xfd, 0xe7, 0x25],
+];
+
+const MAGMA: [[u8; 3]; 11] = [
+    [0x00, 0x00, 0x04],
+    [0x14, 0x0e, 0x36],
+    [0x3b, 0x0f, 0x70],
+    [0x64, 0x1a, 0x80],
+    [0x8c, 0x29, 0x81],
+    [0xb7, 0x37, 0x79],
+    [0xde, 0x49, 0x68],
+    [0xf7, 0x70, 0x5c],
+    [0xfe, 0x9f, 0x6d],
+    [0xfe, 0xcf, 0x92],
+    [0xfc, 0xfd, 0xbf],
+];
+
+const INFERNO: [[u8; 3]; 11] = [
+    [0x00, 0x00, 0x04],
+    [0x16, 0x0b, 0x39],
+    [0x42, 0x0a, 0x68],
+    [0x6a, 0x17, 0x6e],
+    [0x93, 0x26, 0x67],
+    [0xbc, 0x37, 0x54],
+    [0xdd, 0x51, 0x3a],
+    [0xf3, 0x78, 0x19],
+    [0xfc, 0xa5, 0x0a],
+    [0xf6, 0xd7, 0x46],
+    [0xfc, 0xff, 0xa4],
+];
+
+const PLASMA: [[u8; 3]; 11] = [
+    [0x0d, 0x08, 0x87],
+    [0x41, 0x04, 0x9d],
+    [0x6a, 0x00, 0xa8],
+    [0x8f, 0x0d, 0xa4],
+    [0xb1, 0x2a, 0x90],
+    [0xcc, 0x47, 0x78],
+    [0xe1, 0x64, 0x62],
+    [0xf2, 0x84, 0x4b],
+    [0xfc, 0xa6, 0x36],
+    [0xfc, 0xce, 0x25],
+    [0xf0, 0xf9, 0x21],
+];
+
+const COOLWARM: [[u8; 3]; 8] = [
+    [0x3b, 0x4c, 0xc0],
+    [0x67, 0x88, 0xee],
+    [0x9a, 0xbb, 0xff],
+    [0xc9, 0xd7, 0xf0],
+    [0xed, 0xd1, 0xc2],
+    [0xf7, 0xa8, 0x89],
+    [0xe2, 0x69, 0x52],
+    [0xb4, 0x04, 0x26],
+];
+
+const GRAYSCALE: [[u8; 3]; 2] = [[0x00, 0x00, 0x00], [0xff, 0xff, 0xff]];
+
+/// A continuous mapping from normalized values in `[0, 1]` to colors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Colormap {
+    #[default]
+    Viridis,
+    Magma,
+    Inferno,
+    Plasma,
+    /// Diverging blue to red colormap.
+    Coolwarm,
+    Grayscale,
+}
+
+impl Colormap {
+    fn stops(self) -> &'static [[u8; 3]] {
+        match self {
+            Self::Viridis => &VIRIDIS,
+            Self::Magma => &MAGMA,
+            Self::Inferno => &INFERNO,
+            Self::Plasma => &PLASMA,
+            Self::Coolwarm => &COOLWARM,
+            Self::Grayscale => &GRAYSCALE,
+        }
+    }
+
+    /// Returns the color at `t`, linearly interpolating between the colormap's stops. Values
+    /// outside of `[0, 1]` are clamped, and NaN maps to the start of the colormap.
+    pub fn sample(self, t: f32) -> RGBA<f32> {
+        let stops = self.stops();
+        let t = if t.is_nan() { 0.0 } else { t.clamp(0.0, 1.0) };
+
+        let pos = t * (stops.len() - 1) as f32;
+        let i = (pos.floor() as usize).min(stops.len() - 2);
+        let f = pos - i as f32;
+
+        let lerp = |c: usize| {
+            let a = stops[i][c] as f32 / 255.0;
+            let b = stops[i + 1][c] as f32 / 255.0;
+            a + (b - a) * f
+        };
+
+        RGBA {
+            r: lerp(0),
+            g: lerp(1),
+            b: lerp(2),
+            a: 1.0,
+        }
+    }
+
+    /// Returns the color of `value` after normalizing it to `[0, 1]` with the `(min, max)` range.
+    pub fn sample_range(self, value: f32, (min, max): (f32, f32)) -> RGBA<f32> {
+        let t = if max > min {
+            (value - min) / (max - min)
+        } else {
+            0.5
+        };
+        self.sample(t)
+    }
+}
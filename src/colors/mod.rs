@@ -1,3 +1,5 @@
+pub mod colormap;
+
 use rgb::RGBA;
 
 fn parse_hex_digit(digit: char) -> u32 {
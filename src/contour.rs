@@ -0,0 +1,238 @@
+/// A scalar field sampled on a regular grid. `values[row][col]` is the value at the grid node
+/// `col` along x and `row` along y, with the first row and column at the start of the extents.
+#[derive(Debug, Clone, Copy)]
+pub struct Grid<'a> {
+    pub values: &'a [Vec<f32>],
+    pub x_extent: (f32, f32),
+    pub y_extent: (f32, f32),
+}
+
+impl Grid<'_> {
+    pub fn rows(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn cols(&self) -> usize {
+        self.values.iter().map(Vec::len).min().unwrap_or(0)
+    }
+
+    /// Iterates over the grid's cells, each given by the position and value of its corners in
+    /// counter-clockwise order starting from the bottom left.
+    fn cells(&self) -> impl Iterator<Item = [(f32, f32, f32); 4]> + '_ {
+        let (rows, cols) = (self.rows(), self.cols());
+        let (x0, x1) = self.x_extent;
+        let (y0, y1) = self.y_extent;
+        let dx = (x1 - x0) / cols.saturating_sub(1).max(1) as f32;
+        let dy = (y1 - y0) / rows.saturating_sub(1).max(1) as f32;
+        let node = move |row: usize, col: usize| {
+            (
+                x0 + dx * col as f32,
+                y0 + dy * row as f32,
+                self.values[row][col],
+            )
+        };
+
+        (0..rows.saturating_sub(1))
+            .flat_map(move |row| (0..cols.saturating_sub(1)).map(move |col| (row, col)))
+            .map(move |(row, col)| {
+                [
+                    node(row, col),
+                    node(row, col + 1),
+                    node(row + 1, col + 1),
+                    node(row + 1, col),
+                ]
+            })
+            .filter(|corners| corners.iter().all(|(_, _, z)| z.is_finite()))
+    }
+
+    /// Returns the finite minimum and maximum of the grid's values.
+    pub fn range(&self) -> Option<(f32, f32)> {
+        self.values
+            .iter()
+            .flatten()
+            .copied()
+            .filter(|v| v.is_finite())
+            .fold(None, |acc, v| {
+                Some(acc.map_or((v, v), |(lo, hi): (f32, f32)| (lo.min(v), hi.max(v))))
+            })
+    }
+}
+
+/// Returns `n` levels evenly spaced strictly inside `(min, max)`.
+pub fn auto_levels((min, max): (f32, f32), n: usize) -> Vec<f32> {
+    let step = (max - min) / (n + 1) as f32;
+    (1..=n).map(|i| min + step * i as f32).collect()
+}
+
+/// Linearly interpolates the point where the value `level` is crossed between two nodes.
+fn crossing(
+    (x0, y0, z0): (f32, f32, f32),
+    (x1, y1, z1): (f32, f32, f32),
+    level: f32,
+) -> (f32, f32) {
+    let t = if z1 != z0 {
+        (level - z0) / (z1 - z0)
+    } else {
+        0.5
+    };
+    (x0 + (x1 - x0) * t, y0 + (y1 - y0) * t)
+}
+
+/// Computes the line segments along which the field equals `level` using marching squares.
+/// Ambiguous saddle cells are resolved with the average of the cell's corners.
+pub fn iso_lines(grid: Grid, level: f32) -> Vec<[(f32, f32); 2]> {
+    // Edges of a cell: 0 = bottom, 1 = right, 2 = top, 3 = left.
+    const EDGES: [(usize, usize); 4] = [(0, 1), (1, 2), (3, 2), (0, 3)];
+
+    let mut segments = Vec::new();
+    for corners in grid.cells() {
+        let case = corners.iter().enumerate().fold(0, |acc, (i, (_, _, z))| {
+            acc | (usize::from(*z >= level) << i)
+        });
+        let center_above = corners.iter().map(|(_, _, z)| z).sum::<f32>() / 4.0 >= level;
+
+        let edge_pairs: &[(usize, usize)] = match case {
+            1 | 14 => &[(3, 0)],
+            2 | 13 => &[(0, 1)],
+            3 | 12 => &[(3, 1)],
+            4 | 11 => &[(1, 2)],
+            6 | 9 => &[(0, 2)],
+            7 | 8 => &[(3, 2)],
+            5 if center_above => &[(0, 1), (3, 2)],
+            5 => &[(3, 0), (1, 2)],
+            10 if center_above => &[(3, 0), (1, 2)],
+            10 => &[(0, 1), (3, 2)],
+            _ => &[],
+        };
+
+        let point = |edge: usize| {
+            let (a, b) = EDGES[edge];
+            crossing(corners[a], corners[b], level)
+        };
+        segments.extend(edge_pairs.iter().map(|(a, b)| [point(*a), point(*b)]));
+    }
+    segments
+}
+
+/// Clips a polygon with a value at each vertex to the region where `inside` holds for the
+/// interpolated value.
+fn clip(
+    polygon: &[(f32, f32, f32)],
+    level: f32,
+    inside: impl Fn(f32) -> bool,
+) -> Vec<(f32, f32, f32)> {
+    let mut out = Vec::with_capacity(polygon.len() + 2);
+    for (i, &p) in polygon.iter().enumerate() {
+        let q = polygon[(i + 1) % polygon.len()];
+        if inside(p.2) {
+            out.push(p);
+        }
+        if inside(p.2) != inside(q.2) {
+            let (x, y) = crossing(p, q, level);
+            out.push((x, y, level));
+        }
+    }
+    out
+}
+
+/// Computes the polygons covering the region where `lo <= value <= hi`. Each cell is split into
+/// two triangles, which are clipped against both levels.
+pub fn iso_band(grid: Grid, (lo, hi): (f32, f32)) -> Vec<Vec<(f32, f32)>> {
+    let mut polygons = Vec::new();
+    for [a, b, c, d] in grid.cells() {
+        for triangle in [[a, b, c], [a, c, d]] {
+            let (min, max) = triangle
+                .iter()
+                .fold((f32::MAX, f32::MIN), |(min, max), (_, _, z)| {
+                    (min.min(*z), max.max(*z))
+                });
+            if max < lo || min > hi {
+                continue;
+            }
+
+            let polygon = clip(&triangle, lo, |z| z >= lo);
+            let polygon = clip(&polygon, hi, |z| z <= hi);
+            if polygon.len() >= 3 {
+                polygons.push(polygon.into_iter().map(|(x, y, _)| (x, y)).collect());
+            }
+        }
+    }
+    polygons
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid(values: &[Vec<f32>]) -> Grid<'_> {
+        Grid {
+            values,
+            x_extent: (0.0, 1.0),
+            y_extent: (0.0, 1.0),
+        }
+    }
+
+    /// Returns the total area of the polygons, by the shoelace formula.
+    fn area(polygons: &[Vec<(f32, f32)>]) -> f32 {
+        polygons
+            .iter()
+            .map(|polygon| {
+                let twice = (0..polygon.len())
+                    .map(|i| {
+                        let ((x0, y0), (x1, y1)) = (polygon[i], polygon[(i + 1) % polygon.len()]);
+                        x0 * y1 - x1 * y0
+                    })
+                    .sum::<f32>();
+                twice.abs() / 2.0
+            })
+            .sum()
+    }
+
+    #[test]
+    fn auto_levels_are_inside_the_range() {
+        assert_eq!(auto_levels((0.0, 10.0), 4), vec![2.0, 4.0, 6.0, 8.0]);
+        assert_eq!(auto_levels((0.0, 1.0), 0), Vec::<f32>::new());
+    }
+
+    #[test]
+    fn range_skips_values_that_are_not_finite() {
+        let values = vec![vec![1.0, f32::NAN], vec![-2.0, 3.0]];
+        assert_eq!(grid(&values).range(), Some((-2.0, 3.0)));
+        assert_eq!(grid(&[]).range(), None);
+    }
+
+    #[test]
+    fn iso_lines_interpolate_crossings() {
+        // increases from 0 on the left to 1 on the right
+        let values = vec![vec![0.0, 1.0], vec![0.0, 1.0]];
+        assert_eq!(
+            iso_lines(grid(&values), 0.25),
+            vec![[(0.25, 0.0), (0.25, 1.0)]]
+        );
+        assert!(iso_lines(grid(&values), 2.0).is_empty());
+    }
+
+    #[test]
+    fn saddles_get_two_segments() {
+        let values = vec![vec![1.0, 0.0], vec![0.0, 1.0]];
+        assert_eq!(iso_lines(grid(&values), 0.5).len(), 2);
+    }
+
+    #[test]
+    fn cells_with_missing_values_are_skipped() {
+        let values = vec![vec![0.0, 1.0, 1.0], vec![0.0, f32::NAN, 1.0]];
+        assert!(iso_lines(grid(&values), 0.5).is_empty());
+    }
+
+    #[test]
+    fn iso_bands_cover_the_region_between_the_levels() {
+        let values = vec![vec![0.0, 1.0], vec![0.0, 1.0]];
+        let full = iso_band(grid(&values), (-1.0, 2.0));
+        assert!((area(&full) - 1.0).abs() < 1e-6);
+        let right = iso_band(grid(&values), (0.5, 2.0));
+        assert!((area(&right) - 0.5).abs() < 1e-6);
+        let middle = iso_band(grid(&values), (0.25, 0.75));
+        assert!((area(&middle) - 0.5).abs() < 1e-6);
+        assert!(iso_band(grid(&values), (2.0, 3.0)).is_empty());
+    }
+}
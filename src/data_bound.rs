@@ -4,15 +4,28 @@ pub trait Extendable {
     fn extend(self, bound: DataBound) -> DataBound;
 }
 
+/// Combines two limits with `f`, ignoring a limit that is not finite.
+fn finite(a: f32, b: f32, f: impl Fn(f32, f32) -> f32) -> f32 {
+    match (a.is_finite(), b.is_finite()) {
+        (true, true) => f(a, b),
+        (true, false) => a,
+        _ => b,
+    }
+}
+
 impl Extendable for f32 {
     fn extend(self, bound: DataBound) -> DataBound {
-        DataBound(self.min(bound.0), self.max(bound.1))
+        DataBound::from(self).extend(bound)
     }
 }
 
+/// Non-finite limits are ignored, so that infinite or missing values don't stretch the bound.
 impl Extendable for DataBound {
     fn extend(self, bound: DataBound) -> DataBound {
-        DataBound(self.0.min(bound.0), self.1.max(bound.1))
+        DataBound(
+            finite(self.0, bound.0, f32::min),
+            finite(self.1, bound.1, f32::max),
+        )
     }
 }
 
@@ -44,6 +57,11 @@ impl DataBound {
         other.extend(self)
     }
 
+    /// Whether both limits are finite numbers.
+    pub fn is_finite(self) -> bool {
+        self.0.is_finite() && self.1.is_finite()
+    }
+
     pub fn as_tuple(self) -> (f32, f32) {
         (self.0, self.1)
     }
@@ -0,0 +1,62 @@
+use rgb::RGBA;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum HAlign {
+    Left,
+    Center,
+    Right,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum VAlign {
+    Top,
+    Center,
+    Bottom,
+}
+
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct AnnotationStyle {
+    pub font_size: f32,
+    pub color: RGBA<f32>,
+    /// Alignment of the text relative to its position.
+    pub h_align: HAlign,
+    pub v_align: VAlign,
+}
+
+impl std::default::Default for AnnotationStyle {
+    fn default() -> Self {
+        Self {
+            font_size: 12.0,
+            color: RGBA::new(0.0, 0.0, 0.0, 1.0),
+            h_align: HAlign::Center,
+            v_align: VAlign::Center,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum AnnotationKind {
+    /// Text at the annotation's position.
+    Text,
+    /// Text in a box at the annotation's position, with an arrow pointing at `target`.
+    Arrow { target: (f32, f32) },
+    /// A marker at the annotation's position, labeled with the text.
+    Marker,
+}
+
+/// A callout drawn over a chart's series. Positions are in data coordinates.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Annotation {
+    pub text: String,
+    pub position: (f32, f32),
+    pub kind: AnnotationKind,
+    pub style: AnnotationStyle,
+}
@@ -0,0 +1,74 @@
+use rgb::RGBA;
+
+use super::annotation::{Annotation, AnnotationKind, AnnotationStyle, HAlign, VAlign};
+
+pub struct AnnotationBuilder {
+    pub a: Annotation,
+}
+
+impl std::default::Default for AnnotationBuilder {
+    fn default() -> Self {
+        Self {
+            a: Annotation {
+                text: "".to_string(),
+                position: (0.0, 0.0),
+                kind: AnnotationKind::Text,
+                style: Default::default(),
+            },
+        }
+    }
+}
+
+impl AnnotationBuilder {
+    /// Places `text` at `at`.
+    pub fn text(mut self, text: impl ToString, at: (f32, f32)) -> Self {
+        self.a.text = text.to_string();
+        self.a.position = at;
+        self.a.kind = AnnotationKind::Text;
+        self
+    }
+
+    /// Places `text` in a box at `at`, with an arrow pointing at `target`.
+    pub fn arrow(mut self, text: impl ToString, at: (f32, f32), target: (f32, f32)) -> Self {
+        self.a.text = text.to_string();
+        self.a.position = at;
+        self.a.kind = AnnotationKind::Arrow { target };
+        self
+    }
+
+    /// Marks the point `at`, labeled with `text` above and to its right.
+    pub fn marker(mut self, text: impl ToString, at: (f32, f32)) -> Self {
+        self.a.text = text.to_string();
+        self.a.position = at;
+        self.a.kind = AnnotationKind::Marker;
+        self.a.style.h_align = HAlign::Left;
+        self.a.style.v_align = VAlign::Bottom;
+        self
+    }
+
+    pub fn font_size(mut self, font_size: f32) -> Self {
+        self.a.style.font_size = font_size;
+        self
+    }
+
+    pub fn color(mut self, color: impl Into<RGBA<f32>>) -> Self {
+        self.a.style.color = color.into();
+        self
+    }
+
+    /// Sets how the text is aligned relative to its position.
+    pub fn align(mut self, h_align: HAlign, v_align: VAlign) -> Self {
+        self.a.style.h_align = h_align;
+        self.a.style.v_align = v_align;
+        self
+    }
+
+    pub fn style(mut self, style: AnnotationStyle) -> Self {
+        self.a.style = style;
+        self
+    }
+
+    pub fn build(self) -> Annotation {
+        self.a
+    }
+}
@@ -0,0 +1,250 @@
+use crate::data_bound::DataBound;
+
+use super::{ticks::Ticks, time::TimeAxis};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Rounds a step between ticks up to 1, 2 or 5 times a power of ten.
+pub fn nice_step(raw: f32) -> f32 {
+    if !(raw > 0.0 && raw.is_finite()) {
+        return 1.0;
+    }
+    let magnitude = 10f32.powf(raw.log10().floor());
+    let nice = match raw / magnitude {
+        f if f <= 1.0 => 1.0,
+        f if f <= 2.0 => 2.0,
+        f if f <= 5.0 => 5.0,
+        _ => 10.0,
+    };
+    nice * magnitude
+}
+
+/// How values are spaced along an axis.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum AxisScale {
+    #[default]
+    Linear,
+    Log10,
+    Log2,
+    /// Natural logarithm.
+    Ln,
+    /// Logarithmic away from zero and linear within `threshold` of it, so that the axis can
+    /// span zero and negative values.
+    SymLog {
+        threshold: f32,
+    },
+}
+
+impl AxisScale {
+    /// Returns the base of logarithmic scales.
+    pub fn base(self) -> Option<f32> {
+        match self {
+            Self::Log10 | Self::SymLog { .. } => Some(10.0),
+            Self::Log2 => Some(2.0),
+            Self::Ln => Some(std::f32::consts::E),
+            Self::Linear => None,
+        }
+    }
+
+    /// Whether the scale is only defined for positive values.
+    pub fn is_log(self) -> bool {
+        matches!(self, Self::Log10 | Self::Log2 | Self::Ln)
+    }
+
+    /// Returns the threshold within which symmetric-log scales are linear. It is kept positive
+    /// so that the mapping stays finite, and falls back to 1 when it is not a number.
+    pub fn linear_threshold(self) -> Option<f32> {
+        match self {
+            Self::SymLog { threshold } if threshold.is_finite() => {
+                Some(threshold.abs().max(f32::EPSILON))
+            }
+            Self::SymLog { .. } => Some(1.0),
+            _ => None,
+        }
+    }
+
+    /// Maps a value onto the space in which the axis is linear.
+    pub fn forward(self, value: f32) -> f32 {
+        match self {
+            Self::Linear => value,
+            Self::Log10 => value.log10(),
+            Self::Log2 => value.log2(),
+            Self::Ln => value.ln(),
+            Self::SymLog { .. } => {
+                let threshold = self.linear_threshold().unwrap_or(1.0);
+                value.signum() * (1.0 + value.abs() / threshold).log10()
+            }
+        }
+    }
+
+    /// The inverse of [`AxisScale::forward`].
+    pub fn inverse(self, value: f32) -> f32 {
+        match self {
+            Self::Linear => value,
+            Self::Log10 => 10f32.powf(value),
+            Self::Log2 => value.exp2(),
+            Self::Ln => value.exp(),
+            Self::SymLog { .. } => {
+                let threshold = self.linear_threshold().unwrap_or(1.0);
+                value.signum() * threshold * (10f32.powf(value.abs()) - 1.0)
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Axis {
+    pub scale: AxisScale,
+    /// Fixes the start of the axis, instead of fitting it to the data. Without a `max`, a start
+    /// past the data shifts the end of the axis beyond it.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub min: Option<f32>,
+    /// Fixes the end of the axis, instead of fitting it to the data. Without a `min`, an end
+    /// before the data shifts the start of the axis below it.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub max: Option<f32>,
+    /// Extends the data's range on both sides, as a fraction of its length.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub padding: f32,
+    /// Runs the axis from its end to its start, such as top to bottom for y axes.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub inverted: bool,
+    /// Rounds the fitted range outwards to a multiple of the tick step, or to powers of the base
+    /// for logarithmic axes. Fixed limits are kept as they are.
+    ///
+    /// Off by default: rounding would move the edges of existing charts, and leave margins around
+    /// heatmaps and images whose extents are exactly those of their cells.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub nice: bool,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub ticks: Ticks,
+    /// Shows the axis' values as times. Time axes are always linear.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub time: Option<TimeAxis>,
+    /// Names the axis' integer positions, with the value `i` placed at `categories[i]`.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub categories: Vec<String>,
+}
+
+impl Axis {
+    /// Returns the range shown along the axis for data within `bound`, from the start of the
+    /// axis to its end, so that inverted axes return a decreasing range.
+    ///
+    /// The data's range is widened when it is empty or zero-width, padded, rounded when `nice`
+    /// is set, and then overridden by the fixed limits. Logarithmic axes start at
+    /// `smallest_positive`, the smallest positive value of their data, when the bound reaches
+    /// zero or below, and ignore limits that are not positive.
+    pub fn range(&self, bound: Option<DataBound>, smallest_positive: Option<f32>) -> (f32, f32) {
+        // time axes are always linear
+        let scale = if self.time.is_some() {
+            AxisScale::Linear
+        } else {
+            self.scale
+        };
+
+        // charts without data get a placeholder range
+        let (lo, hi) = match bound.filter(|b| b.is_finite()) {
+            Some(bound) => bound.as_tuple(),
+            None if scale.is_log() => (1.0, 10.0),
+            None => (0.0, 1.0),
+        };
+        let (lo, hi) = if scale.is_log() {
+            let hi = if hi > 0.0 { hi } else { 1.0 };
+            let lo = if lo > 0.0 {
+                lo
+            } else {
+                smallest_positive
+                    .filter(|v| *v <= hi)
+                    .unwrap_or(hi / 1000.0)
+            };
+            (lo, hi)
+        } else {
+            (lo, hi)
+        };
+        let (lo, hi) = self.widen(scale, lo, hi);
+
+        // padding is relative to the axis' length as drawn, which differs from the data's for
+        // nonlinear scales
+        let (f0, f1) = (scale.forward(lo), scale.forward(hi));
+        let pad = (f1 - f0) * self.padding;
+        let (lo, hi) = (scale.inverse(f0 - pad), scale.inverse(f1 + pad));
+
+        let (lo, hi) = if self.nice && self.categories.is_empty() && self.time.is_none() {
+            Self::round(scale, lo, hi)
+        } else {
+            (lo, hi)
+        };
+
+        let valid = |v: &f32| v.is_finite() && (!scale.is_log() || *v > 0.0);
+        // a single limit past the other end of the data moves that end along, keeping the
+        // length of the range as drawn
+        let length = scale.forward(hi) - scale.forward(lo);
+        let (lo, hi) = match (self.min.filter(valid), self.max.filter(valid)) {
+            (Some(min), None) if min >= hi => (min, scale.inverse(scale.forward(min) + length)),
+            (None, Some(max)) if max <= lo => (scale.inverse(scale.forward(max) - length), max),
+            (min, max) => (min.unwrap_or(lo), max.unwrap_or(hi)),
+        };
+        let (lo, hi) = self.widen(scale, lo, hi);
+        if self.inverted {
+            (hi, lo)
+        } else {
+            (lo, hi)
+        }
+    }
+
+    /// Widens a zero-width range around its value, by an hour on time axes and otherwise by a
+    /// tenth of the value's magnitude as drawn.
+    fn widen(&self, scale: AxisScale, lo: f32, hi: f32) -> (f32, f32) {
+        if lo != hi {
+            return (lo, hi);
+        }
+        if self.time.is_some() {
+            return (lo - 3600.0, hi + 3600.0);
+        }
+        let f = scale.forward(lo);
+        let d = if f == 0.0 { 1.0 } else { f.abs() * 0.1 };
+        (scale.inverse(f - d), scale.inverse(f + d))
+    }
+
+    /// Rounds a range outwards to nice numbers.
+    fn round(scale: AxisScale, lo: f32, hi: f32) -> (f32, f32) {
+        match scale {
+            AxisScale::Linear => {
+                let step = nice_step((hi - lo) / 5.0);
+                ((lo / step).floor() * step, (hi / step).ceil() * step)
+            }
+            AxisScale::Log10 | AxisScale::Log2 | AxisScale::Ln => (
+                // tolerates the rounding error of logarithms of exact powers
+                scale.inverse((scale.forward(lo) + 1e-4).floor()),
+                scale.inverse((scale.forward(hi) - 1e-4).ceil()),
+            ),
+            AxisScale::SymLog { .. } => (lo, hi),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn symlog_scales_stay_finite_without_a_threshold() {
+        for threshold in [0.0, -0.0, f32::NAN, f32::INFINITY] {
+            let scale = AxisScale::SymLog { threshold };
+            for value in [-100.0, -1.0, 0.0, 1.0, 100.0] {
+                let f = scale.forward(value);
+                assert!(f.is_finite(), "{threshold}: {value} maps to {f}");
+                assert!((scale.inverse(f) - value).abs() <= value.abs() * 1e-3);
+            }
+            let axis = Axis {
+                scale,
+                ..Default::default()
+            };
+            let (lo, hi) = axis.range(Some(DataBound::from((-100.0, 100.0))), None);
+            assert!(lo.is_finite() && hi.is_finite() && lo < hi);
+        }
+    }
+}
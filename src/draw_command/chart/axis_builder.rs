@@ -0,0 +1,83 @@
+use std::sync::Arc;
+
+use super::{
+    axis::{Axis, AxisScale},
+    ticks::{Tick, TickFormat, TickFormatter},
+};
+
+#[derive(Default)]
+pub struct AxisBuilder {
+    pub a: Axis,
+}
+
+impl AxisBuilder {
+    pub fn scale(mut self, scale: AxisScale) -> Self {
+        self.a.scale = scale;
+        self
+    }
+
+    pub fn min(mut self, min: f32) -> Self {
+        self.a.min = Some(min);
+        self
+    }
+
+    pub fn max(mut self, max: f32) -> Self {
+        self.a.max = Some(max);
+        self
+    }
+
+    pub fn limits(self, min: f32, max: f32) -> Self {
+        self.min(min).max(max)
+    }
+
+    /// Extends the data's range on both sides by `fraction` of its length.
+    pub fn padding(mut self, fraction: f32) -> Self {
+        self.a.padding = fraction;
+        self
+    }
+
+    pub fn inverted(mut self) -> Self {
+        self.a.inverted = true;
+        self
+    }
+
+    /// Rounds the fitted range outwards to nice numbers, which axes do not do by default.
+    pub fn nice(mut self) -> Self {
+        self.a.nice = true;
+        self
+    }
+
+    /// Labels at most `n` ticks.
+    pub fn major_ticks(mut self, n: usize) -> Self {
+        self.a.ticks.major = Some(n);
+        self
+    }
+
+    /// Draws at most `n` unlabeled grid lines between two labeled ticks.
+    pub fn minor_ticks(mut self, n: usize) -> Self {
+        self.a.ticks.minor = n;
+        self
+    }
+
+    /// Places the ticks at the given positions. Each tick is a value (`f32`), or a value with
+    /// its label (`(f32, label)`).
+    pub fn ticks(mut self, ticks: impl IntoIterator<Item = impl Into<Tick>>) -> Self {
+        self.a.ticks.explicit = ticks.into_iter().map(Into::into).collect();
+        self
+    }
+
+    pub fn format(mut self, format: TickFormat) -> Self {
+        self.a.ticks.format = format;
+        self
+    }
+
+    /// Labels the ticks with `f`. The closure is not serialized: a serialized chart labels the
+    /// ticks with [`TickFormat::Auto`] instead.
+    pub fn format_with(self, f: impl Fn(f32) -> String + Send + Sync + 'static) -> Self {
+        self.format(TickFormat::Custom(TickFormatter(Arc::new(f))))
+    }
+
+    pub fn build(self) -> Axis {
+        self.a
+    }
+}
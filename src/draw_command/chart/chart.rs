@@ -1,6 +1,11 @@
-use crate::data_bound::DataBound;
+use itertools::chain;
 
-use super::series::ChartSeries;
+use crate::data_bound::{DataBound, DataBound2D};
+
+use super::{
+    annotation::Annotation, axis::Axis, decoration::Decoration, legend::Legend, mesh::Mesh,
+    polar::Polar, series::ChartSeries, series_ty::ChartSeriesType,
+};
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -17,24 +22,38 @@ pub struct Chart {
     pub y_label_r: String,
     pub title: String,
     pub margin: i32,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub x_axis: Axis,
+    /// The axis of the primary series.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub y_axis_l: Axis,
+    /// The axis of the secondary series.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub y_axis_r: Axis,
+    /// Draws the chart in polar coordinates, with the series' data as `(theta, r)` pairs. Only
+    /// line and scatter series are drawn, see [`ChartSeriesType::supports_polar`].
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub polar: Option<Polar>,
+    /// Callouts drawn over the series. They do not affect the chart's bounds.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub annotations: Vec<Annotation>,
+    /// Reference lines and bands, placed on the primary axes.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub decorations: Vec<Decoration>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub legend: Legend,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub mesh: Mesh,
 }
 
 impl Chart {
     /// Returns the bounds of the chart's data: the x bounds, y, bounds and secondary y bounds.
     pub fn bounds(&self) -> (Option<DataBound>, Option<DataBound>, Option<DataBound>) {
-        let l = self
-            .series_l
-            .iter()
-            .filter_map(|seires| seires.bounds())
-            .reduce(|acc, x| acc.extend_to_include_bound2d(x));
+        let l = Self::fit_arrows(&self.series_l);
         let lx = l.map(|b| b.x);
         let ly = l.map(|b| b.y);
 
-        let r = self
-            .series_r
-            .iter()
-            .filter_map(|seires| seires.bounds())
-            .reduce(|acc, x| acc.extend_to_include_bound2d(x));
+        let r = Self::fit_arrows(&self.series_r);
 
         let rx = r.map(|b| b.x);
         let ry = r.map(|b| b.y);
@@ -44,6 +63,122 @@ impl Chart {
             .filter_map(|x| x)
             .reduce(DataBound::extend_to_include);
 
+        let union = |a: Option<DataBound>, b: Option<DataBound>| {
+            [a, b]
+                .into_iter()
+                .flatten()
+                .reduce(DataBound::extend_to_include)
+        };
+        let (x, ly) = self
+            .decorations
+            .iter()
+            .filter(|decoration| decoration.in_bounds)
+            .map(Decoration::bounds)
+            .fold((x, ly), |(x, y), (dx, dy)| (union(x, dx), union(y, dy)));
+
         (x, ly, ry)
     }
+
+    /// Returns the bounds of the series, which do not include automatically scaled arrows.
+    fn series_bounds(series: &[ChartSeries]) -> Option<DataBound2D> {
+        series
+            .iter()
+            .filter_map(|seires| seires.bounds())
+            .reduce(|acc, x| acc.extend_to_include_bound2d(x))
+    }
+
+    /// Returns the smaller of the width and height of `bound`, or of its larger side when it
+    /// is zero-width.
+    fn smaller_side(bound: Option<DataBound2D>) -> f32 {
+        let Some(bound) = bound else {
+            return 1.0;
+        };
+        let length = |b: DataBound| {
+            let (lo, hi) = b.as_tuple();
+            hi - lo
+        };
+        let (w, h) = (length(bound.x), length(bound.y));
+        if w.min(h) > 0.0 {
+            w.min(h)
+        } else {
+            w.max(h).max(1.0)
+        }
+    }
+
+    /// Returns the bounds of the series, extended by their automatically scaled arrows.
+    fn fit_arrows(series: &[ChartSeries]) -> Option<DataBound2D> {
+        let bound = Self::series_bounds(series);
+        let size = Self::smaller_side(bound);
+        let arrows = series
+            .iter()
+            .filter_map(|series| match &series.chart_series_type {
+                ChartSeriesType::Quiver(q) if q.scale.is_none() => q.bounds(q.scale(size)),
+                _ => None,
+            });
+        chain!(bound, arrows).reduce(DataBound2D::extend_to_include_bound2d)
+    }
+
+    /// Returns the size automatically scaled arrows are drawn relative to: the smaller of the
+    /// width and height of the bounds of the primary or secondary series, without those arrows.
+    pub fn arrow_size(&self, secondary: bool) -> f32 {
+        let series = if secondary {
+            &self.series_r
+        } else {
+            &self.series_l
+        };
+        Self::smaller_side(Self::series_bounds(series))
+    }
+
+    /// Returns the ranges shown along the x axis, the y axis and the secondary y axis.
+    pub fn axis_ranges(&self) -> ((f32, f32), (f32, f32), (f32, f32)) {
+        fn smallest_positive<'a>(
+            series: impl IntoIterator<Item = &'a ChartSeries>,
+            value: impl Fn(&(f32, f32)) -> f32,
+        ) -> Option<f32> {
+            series
+                .into_iter()
+                .flat_map(|series| series.data.iter().map(&value))
+                .filter(|v| *v > 0.0 && v.is_finite())
+                .reduce(f32::min)
+        }
+
+        let (mut x, y, y_r) = self.bounds();
+        // each category occupies a unit wide slot centered on its index
+        if !self.x_axis.categories.is_empty() {
+            let slots = DataBound::from((-0.5, self.x_axis.categories.len() as f32 - 0.5));
+            x = Some(x.map_or(slots, |x| x.extend_to_include(slots)));
+        }
+        (
+            self.x_axis.range(
+                x,
+                smallest_positive(chain!(&self.series_l, &self.series_r), |p| p.0),
+            ),
+            self.y_axis_l
+                .range(y, smallest_positive(&self.series_l, |p| p.1)),
+            self.y_axis_r
+                .range(y_r, smallest_positive(&self.series_r, |p| p.1)),
+        )
+    }
+
+    /// Returns the largest radius of the series' data, when drawn in polar coordinates.
+    pub fn r_max(&self) -> Option<f32> {
+        chain!(&self.series_l, &self.series_r)
+            .filter(|series| series.chart_series_type.supports_polar())
+            .flat_map(|series| series.data.iter().map(|(_, r)| r.abs()))
+            .filter(|r| r.is_finite())
+            .reduce(f32::max)
+    }
+
+    /// Returns the names of the categories placed along the x axis, indexed by their x position.
+    /// These are the x axis' categories, or those of distribution series (box and violin plots).
+    pub fn x_categories(&self) -> Vec<&str> {
+        if !self.x_axis.categories.is_empty() {
+            return self.x_axis.categories.iter().map(String::as_str).collect();
+        }
+        chain!(&self.series_l, &self.series_r)
+            .filter_map(|series| series.chart_series_type.groups())
+            .max_by_key(|groups| groups.len())
+            .map(|groups| groups.iter().map(|group| group.name.as_str()).collect())
+            .unwrap_or_default()
+    }
 }
@@ -1,12 +1,20 @@
+use itertools::chain;
+
 use crate::colors::Palette;
 
-use super::{chart::Chart, series_builder::ChartSeriesBuilder, IntoChartSeriesBuilder};
+use super::{
+    annotation::Annotation, annotation_builder::AnnotationBuilder, axis::AxisScale,
+    axis_builder::AxisBuilder, chart::Chart, decoration::Decoration,
+    decoration_builder::DecorationBuilder, legend::LegendPosition, mesh_builder::MeshBuilder,
+    polar::AngleUnit, series_builder::ChartSeriesBuilder, time::UtcOffset, IntoChartSeriesBuilder,
+};
 
 pub struct ChartBuilder {
     c: Chart,
     p: Palette,
     series_l: Vec<ChartSeriesBuilder>,
     series_r: Vec<ChartSeriesBuilder>,
+    sort_categories: bool,
 }
 
 impl std::default::Default for ChartBuilder {
@@ -20,10 +28,19 @@ impl std::default::Default for ChartBuilder {
                 y_label_r: "".to_string(),
                 title: "".to_string(),
                 margin: 40,
+                x_axis: Default::default(),
+                y_axis_l: Default::default(),
+                y_axis_r: Default::default(),
+                polar: None,
+                annotations: vec![],
+                decorations: vec![],
+                legend: Default::default(),
+                mesh: Default::default(),
             },
             p: Default::default(),
             series_l: vec![],
             series_r: vec![],
+            sort_categories: false,
         }
     }
 }
@@ -55,6 +72,42 @@ impl ChartBuilder {
         self.add_series_r(f(csb))
     }
 
+    pub fn annotate(mut self, annotation: Annotation) -> Self {
+        self.c.annotations.push(annotation);
+        self
+    }
+
+    pub fn annotate_with(self, f: impl FnOnce(AnnotationBuilder) -> AnnotationBuilder) -> Self {
+        let ab = AnnotationBuilder::default();
+        self.annotate(f(ab).build())
+    }
+
+    pub fn decorate(mut self, decoration: Decoration) -> Self {
+        self.c.decorations.push(decoration);
+        self
+    }
+
+    pub fn decorate_with(self, f: impl FnOnce(DecorationBuilder) -> DecorationBuilder) -> Self {
+        let db = DecorationBuilder::default();
+        self.decorate(f(db).build())
+    }
+
+    pub fn legend(mut self, position: LegendPosition) -> Self {
+        self.c.legend.position = position;
+        self
+    }
+
+    /// Lays out the legend's entries in `columns` columns, filled row by row.
+    pub fn legend_columns(mut self, columns: usize) -> Self {
+        self.c.legend.columns = columns.max(1);
+        self
+    }
+
+    pub fn legend_font_size(mut self, size: f32) -> Self {
+        self.c.legend.font_size = size;
+        self
+    }
+
     pub fn x_label(mut self, label: impl ToString) -> Self {
         self.c.x_label = label.to_string();
         self
@@ -70,6 +123,73 @@ impl ChartBuilder {
         self
     }
 
+    /// Configures the x axis, starting from its current settings.
+    pub fn x_axis_with(mut self, f: impl FnOnce(AxisBuilder) -> AxisBuilder) -> Self {
+        let a = std::mem::take(&mut self.c.x_axis);
+        self.c.x_axis = f(AxisBuilder { a }).build();
+        self
+    }
+
+    /// Configures the y axis of the primary series, starting from its current settings.
+    pub fn y_axis_l_with(mut self, f: impl FnOnce(AxisBuilder) -> AxisBuilder) -> Self {
+        let a = std::mem::take(&mut self.c.y_axis_l);
+        self.c.y_axis_l = f(AxisBuilder { a }).build();
+        self
+    }
+
+    /// Configures the y axis of the secondary series, starting from its current settings.
+    pub fn y_axis_r_with(mut self, f: impl FnOnce(AxisBuilder) -> AxisBuilder) -> Self {
+        let a = std::mem::take(&mut self.c.y_axis_r);
+        self.c.y_axis_r = f(AxisBuilder { a }).build();
+        self
+    }
+
+    /// Configures the grid, spines and tick marks, starting from their current settings.
+    pub fn mesh_with(mut self, f: impl FnOnce(MeshBuilder) -> MeshBuilder) -> Self {
+        let m = self.c.mesh;
+        self.c.mesh = f(MeshBuilder { m }).build();
+        self
+    }
+
+    pub fn x_scale(mut self, scale: AxisScale) -> Self {
+        self.c.x_axis.scale = scale;
+        self
+    }
+
+    pub fn y_scale_l(mut self, scale: AxisScale) -> Self {
+        self.c.y_axis_l.scale = scale;
+        self
+    }
+
+    pub fn y_scale_r(mut self, scale: AxisScale) -> Self {
+        self.c.y_axis_r.scale = scale;
+        self
+    }
+
+    /// Sets the categories of the x axis and their order. The categories of categorical data
+    /// that are not listed follow them.
+    pub fn x_categories(mut self, categories: impl IntoIterator<Item = impl ToString>) -> Self {
+        self.c.x_axis.categories = categories.into_iter().map(|c| c.to_string()).collect();
+        self
+    }
+
+    /// Sorts the categories of the x axis by name, instead of ordering them as they first
+    /// appear in the series.
+    pub fn sort_categories(mut self) -> Self {
+        self.sort_categories = true;
+        self
+    }
+
+    /// Labels the ticks of the time x axis in the timezone at `offset` from UTC.
+    pub fn time_zone(mut self, offset: impl Into<UtcOffset>) -> Self {
+        self.c
+            .x_axis
+            .time
+            .get_or_insert_with(Default::default)
+            .utc_offset = offset.into();
+        self
+    }
+
     pub fn title(mut self, title: impl ToString) -> Self {
         self.c.title = title.to_string();
         self
@@ -80,9 +200,53 @@ impl ChartBuilder {
         self
     }
 
-    pub fn build(self) -> Chart {
+    /// Draws the chart in polar coordinates. The series' data are `(theta, r)` pairs, with
+    /// theta in `unit`. Only line and scatter series are drawn, see
+    /// [`ChartSeriesType::supports_polar`](super::series_ty::ChartSeriesType::supports_polar).
+    pub fn polar(mut self, unit: AngleUnit) -> Self {
+        self.c.polar.get_or_insert_with(Default::default).unit = unit;
+        self
+    }
+
+    /// Sets the number of angular and radial grid lines of polar charts. This also draws the
+    /// chart in polar coordinates, with angles in radians unless [`Self::polar`] sets the unit,
+    /// in either order.
+    pub fn polar_grid(mut self, angular_lines: usize, radial_lines: usize) -> Self {
+        let polar = self.c.polar.get_or_insert_with(Default::default);
+        polar.angular_lines = angular_lines;
+        polar.radial_lines = radial_lines;
+        self
+    }
+
+    pub fn build(mut self) -> Chart {
         let mut chart = self.c;
 
+        // time data is measured from the earliest epoch of the series
+        let epoch = chain!(&self.series_l, &self.series_r)
+            .filter_map(|s| s.epoch())
+            .min();
+        if let Some(epoch) = epoch {
+            chart.x_axis.time.get_or_insert_with(Default::default).epoch = epoch;
+            for s in chain!(&mut self.series_l, &mut self.series_r) {
+                s.rebase_time(epoch);
+            }
+        }
+
+        let categories = &mut chart.x_axis.categories;
+        for name in chain!(&self.series_l, &self.series_r).flat_map(|s| s.categories()) {
+            if !categories.contains(name) {
+                categories.push(name.clone());
+            }
+        }
+        if self.sort_categories {
+            categories.sort();
+        }
+        for s in chain!(&mut self.series_l, &mut self.series_r) {
+            if !s.categories().is_empty() {
+                s.remap_categories(categories);
+            }
+        }
+
         let mut colors = self.p.iter();
         for s in self.series_l {
             let s = if let Some(c) = colors.next() {
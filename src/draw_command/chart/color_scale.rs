@@ -0,0 +1,64 @@
+use rgb::RGBA;
+
+use crate::colors::colormap::Colormap;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Colorbar {
+    pub label: String,
+}
+
+/// Maps scalar values onto colors. Limits that are not set are taken from the data.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ColorScale {
+    pub colormap: Colormap,
+    pub vmin: Option<f32>,
+    pub vmax: Option<f32>,
+    /// A colorbar showing the value scale next to the chart.
+    pub colorbar: Option<Colorbar>,
+}
+
+impl std::default::Default for ColorScale {
+    fn default() -> Self {
+        Self {
+            colormap: Default::default(),
+            vmin: None,
+            vmax: None,
+            colorbar: Some(Default::default()),
+        }
+    }
+}
+
+impl ColorScale {
+    /// Returns the range of values that is mapped onto the colormap, from its lower to its upper
+    /// end. Limits given in decreasing order are swapped, and limits that are not finite are
+    /// ignored.
+    pub fn resolve(&self, values: impl IntoIterator<Item = f32>) -> Option<(f32, f32)> {
+        let data = values.into_iter().filter(|v| v.is_finite()).fold(
+            None,
+            |acc: Option<(f32, f32)>, v| {
+                Some(acc.map_or((v, v), |(lo, hi)| (lo.min(v), hi.max(v))))
+            },
+        );
+
+        let (vmin, vmax) = (
+            self.vmin.filter(|v| v.is_finite()),
+            self.vmax.filter(|v| v.is_finite()),
+        );
+        match (vmin, vmax, data) {
+            (Some(a), Some(b), _) => Some((a.min(b), a.max(b))),
+            (Some(lo), None, Some((_, hi))) => Some((lo, hi.max(lo))),
+            (None, Some(hi), Some((lo, _))) => Some((lo.min(hi), hi)),
+            (None, None, data) => data,
+            _ => None,
+        }
+    }
+
+    pub fn color(&self, value: f32, range: (f32, f32)) -> RGBA<f32> {
+        self.colormap.sample_range(value, range)
+    }
+}
@@ -0,0 +1,60 @@
+use rgb::RGBA;
+
+use crate::data_bound::DataBound;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum DecorationKind {
+    /// A line across the chart at a y value.
+    HLine { y: f32 },
+    /// A line across the chart at an x value.
+    VLine { x: f32 },
+    /// A band across the chart between two y values, in either order.
+    HSpan { y: (f32, f32) },
+    /// A band across the chart between two x values, in either order.
+    VSpan { x: (f32, f32) },
+}
+
+/// Whether a decoration is drawn below or above the chart's series.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ZOrder {
+    #[default]
+    Below,
+    Above,
+}
+
+/// A reference line or shaded band spanning the chart, such as a threshold or a time window.
+/// Values are in the coordinates of the primary axes.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Decoration {
+    pub kind: DecorationKind,
+    /// Names the decoration in the legend, unless it is empty.
+    pub label: String,
+    /// The color of lines, or the fill of bands, which is usually translucent.
+    pub color: RGBA<f32>,
+    pub dashed: bool,
+    pub z_order: ZOrder,
+    /// Fits the chart's axes to the decoration, which otherwise may fall outside of them.
+    pub in_bounds: bool,
+}
+
+impl Decoration {
+    /// Returns the x and y extents of the decoration, along the axes it is placed on.
+    pub fn bounds(&self) -> (Option<DataBound>, Option<DataBound>) {
+        match self.kind {
+            DecorationKind::HLine { y } => (None, Some(DataBound::from(y))),
+            DecorationKind::VLine { x } => (Some(DataBound::from(x)), None),
+            DecorationKind::HSpan { y: (a, b) } => {
+                (None, Some(DataBound::from((a.min(b), a.max(b)))))
+            }
+            DecorationKind::VSpan { x: (a, b) } => {
+                (Some(DataBound::from((a.min(b), a.max(b)))), None)
+            }
+        }
+    }
+}
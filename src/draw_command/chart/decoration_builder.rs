@@ -0,0 +1,81 @@
+use rgb::RGBA;
+
+use super::decoration::{Decoration, DecorationKind, ZOrder};
+
+pub struct DecorationBuilder {
+    pub d: Decoration,
+}
+
+impl std::default::Default for DecorationBuilder {
+    fn default() -> Self {
+        Self {
+            d: Decoration {
+                kind: DecorationKind::HLine { y: 0.0 },
+                label: "".to_string(),
+                color: RGBA::new(0.0, 0.0, 0.0, 1.0),
+                dashed: false,
+                z_order: Default::default(),
+                in_bounds: false,
+            },
+        }
+    }
+}
+
+impl DecorationBuilder {
+    /// A line across the chart at `y`.
+    pub fn hline(mut self, y: f32) -> Self {
+        self.d.kind = DecorationKind::HLine { y };
+        self
+    }
+
+    /// A line across the chart at `x`.
+    pub fn vline(mut self, x: f32) -> Self {
+        self.d.kind = DecorationKind::VLine { x };
+        self
+    }
+
+    /// A translucent band across the chart from `y0` to `y1`.
+    pub fn hspan(mut self, y0: f32, y1: f32) -> Self {
+        self.d.kind = DecorationKind::HSpan { y: (y0, y1) };
+        self.d.color = RGBA::new(0.5, 0.5, 0.5, 0.25);
+        self
+    }
+
+    /// A translucent band across the chart from `x0` to `x1`.
+    pub fn vspan(mut self, x0: f32, x1: f32) -> Self {
+        self.d.kind = DecorationKind::VSpan { x: (x0, x1) };
+        self.d.color = RGBA::new(0.5, 0.5, 0.5, 0.25);
+        self
+    }
+
+    pub fn label(mut self, label: impl ToString) -> Self {
+        self.d.label = label.to_string();
+        self
+    }
+
+    pub fn color(mut self, color: impl Into<RGBA<f32>>) -> Self {
+        self.d.color = color.into();
+        self
+    }
+
+    pub fn dashed(mut self) -> Self {
+        self.d.dashed = true;
+        self
+    }
+
+    /// Draws the decoration over the chart's series, instead of under them.
+    pub fn above(mut self) -> Self {
+        self.d.z_order = ZOrder::Above;
+        self
+    }
+
+    /// Fits the chart's axes to the decoration.
+    pub fn in_bounds(mut self) -> Self {
+        self.d.in_bounds = true;
+        self
+    }
+
+    pub fn build(self) -> Decoration {
+        self.d
+    }
+}
@@ -0,0 +1,52 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Where the legend is placed. Inside positions are relative to the plotting area.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum LegendPosition {
+    #[default]
+    UpperLeft,
+    UpperCenter,
+    UpperRight,
+    CenterLeft,
+    Center,
+    CenterRight,
+    LowerLeft,
+    LowerCenter,
+    LowerRight,
+    /// Chooses the inside position that covers the fewest data points.
+    Best,
+    /// Beside the plotting area, which shrinks to make room for it.
+    OutsideRight,
+    /// Below the plotting area, which shrinks to make room for it.
+    OutsideBottom,
+}
+
+impl LegendPosition {
+    /// Returns whether the legend is placed outside of the plotting area.
+    pub fn is_outside(self) -> bool {
+        matches!(self, Self::OutsideRight | Self::OutsideBottom)
+    }
+}
+
+/// The placement and layout of a chart's legend, which lists its named series and labeled
+/// decorations.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(default))]
+pub struct Legend {
+    pub position: LegendPosition,
+    /// The number of columns the entries are laid out in, filled row by row.
+    pub columns: usize,
+    pub font_size: f32,
+}
+
+impl std::default::Default for Legend {
+    fn default() -> Self {
+        Self {
+            position: Default::default(),
+            columns: 1,
+            font_size: 12.0,
+        }
+    }
+}
@@ -0,0 +1,102 @@
+use rgb::RGBA;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The pattern lines are stroked with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum LineDash {
+    #[default]
+    Solid,
+    Dashed,
+    Dotted,
+}
+
+/// The style of a set of grid lines.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct GridLines {
+    pub visible: bool,
+    pub color: RGBA<f32>,
+    /// The width of the lines, in pixels.
+    pub width: u32,
+    pub dash: LineDash,
+}
+
+impl GridLines {
+    /// Returns solid lines in black at `alpha`.
+    fn solid(alpha: f32) -> Self {
+        Self {
+            visible: true,
+            color: RGBA::new(0.0, 0.0, 0.0, alpha),
+            width: 1,
+            dash: LineDash::Solid,
+        }
+    }
+}
+
+/// The grid lines across the chart at the ticks of an axis: the major lines at its labeled
+/// ticks, and the minor lines between them.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(default))]
+pub struct AxisGrid {
+    pub major: GridLines,
+    pub minor: GridLines,
+}
+
+impl std::default::Default for AxisGrid {
+    fn default() -> Self {
+        Self {
+            // as dark as the minor and major lines of plotters' default mesh drawn over each other
+            major: GridLines::solid(0.28),
+            minor: GridLines::solid(0.1),
+        }
+    }
+}
+
+/// The lines bounding the plotting area. The secondary y axis always has its right spine.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(default))]
+pub struct Spines {
+    pub left: bool,
+    pub right: bool,
+    pub top: bool,
+    pub bottom: bool,
+}
+
+impl std::default::Default for Spines {
+    fn default() -> Self {
+        Self {
+            left: true,
+            right: false,
+            top: false,
+            bottom: true,
+        }
+    }
+}
+
+/// Which side of the axes the tick marks are drawn on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum TickDirection {
+    /// Away from the plotting area, toward the labels.
+    #[default]
+    Out,
+    /// Into the plotting area.
+    In,
+    /// No tick marks, only labels.
+    Hidden,
+}
+
+/// The grid, spines and tick marks of a chart.
+#[derive(Debug, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(default))]
+pub struct Mesh {
+    /// The vertical lines, at the ticks of the x axis.
+    pub x_grid: AxisGrid,
+    /// The horizontal lines, at the ticks of the primary y axis.
+    pub y_grid: AxisGrid,
+    pub spines: Spines,
+    pub tick_direction: TickDirection,
+}
@@ -0,0 +1,111 @@
+use rgb::RGBA;
+
+use super::mesh::{GridLines, LineDash, Mesh, Spines, TickDirection};
+
+#[derive(Default)]
+pub struct MeshBuilder {
+    pub m: Mesh,
+}
+
+impl MeshBuilder {
+    /// Hides all grid lines.
+    pub fn no_grid(self) -> Self {
+        self.x_grid(false, false).y_grid(false, false)
+    }
+
+    /// Shows or hides the major and minor grid lines at the ticks of the x axis.
+    pub fn x_grid(mut self, major: bool, minor: bool) -> Self {
+        self.m.x_grid.major.visible = major;
+        self.m.x_grid.minor.visible = minor;
+        self
+    }
+
+    /// Shows or hides the major and minor grid lines at the ticks of the primary y axis.
+    pub fn y_grid(mut self, major: bool, minor: bool) -> Self {
+        self.m.y_grid.major.visible = major;
+        self.m.y_grid.minor.visible = minor;
+        self
+    }
+
+    /// Styles the major grid lines of both axes.
+    pub fn major_grid_style(
+        mut self,
+        color: impl Into<RGBA<f32>>,
+        width: u32,
+        dash: LineDash,
+    ) -> Self {
+        let color = color.into();
+        for lines in [&mut self.m.x_grid.major, &mut self.m.y_grid.major] {
+            *lines = GridLines {
+                color,
+                width,
+                dash,
+                ..*lines
+            };
+        }
+        self
+    }
+
+    /// Styles the minor grid lines of both axes.
+    pub fn minor_grid_style(
+        mut self,
+        color: impl Into<RGBA<f32>>,
+        width: u32,
+        dash: LineDash,
+    ) -> Self {
+        let color = color.into();
+        for lines in [&mut self.m.x_grid.minor, &mut self.m.y_grid.minor] {
+            *lines = GridLines {
+                color,
+                width,
+                dash,
+                ..*lines
+            };
+        }
+        self
+    }
+
+    pub fn x_major_grid(mut self, lines: GridLines) -> Self {
+        self.m.x_grid.major = lines;
+        self
+    }
+
+    pub fn x_minor_grid(mut self, lines: GridLines) -> Self {
+        self.m.x_grid.minor = lines;
+        self
+    }
+
+    pub fn y_major_grid(mut self, lines: GridLines) -> Self {
+        self.m.y_grid.major = lines;
+        self
+    }
+
+    pub fn y_minor_grid(mut self, lines: GridLines) -> Self {
+        self.m.y_grid.minor = lines;
+        self
+    }
+
+    pub fn spines(mut self, spines: Spines) -> Self {
+        self.m.spines = spines;
+        self
+    }
+
+    /// Draws spines on all four sides of the plotting area.
+    pub fn boxed(self) -> Self {
+        self.spines(Spines {
+            left: true,
+            right: true,
+            top: true,
+            bottom: true,
+        })
+    }
+
+    pub fn tick_direction(mut self, direction: TickDirection) -> Self {
+        self.m.tick_direction = direction;
+        self
+    }
+
+    pub fn build(self) -> Mesh {
+        self.m
+    }
+}
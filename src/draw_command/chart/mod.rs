@@ -1,11 +1,29 @@
+pub mod annotation;
+#[cfg(feature = "builder")]
+pub mod annotation_builder;
+pub mod axis;
+#[cfg(feature = "builder")]
+pub mod axis_builder;
 pub mod chart;
 #[cfg(feature = "builder")]
 pub mod chart_builder;
+pub mod color_scale;
+pub mod decoration;
+#[cfg(feature = "builder")]
+pub mod decoration_builder;
+pub mod legend;
+pub mod mesh;
+#[cfg(feature = "builder")]
+pub mod mesh_builder;
+pub mod polar;
 pub mod series;
 #[cfg(feature = "builder")]
 pub mod series_builder;
+pub mod series_error;
 pub mod series_style;
 pub mod series_ty;
+pub mod ticks;
+pub mod time;
 
 #[cfg(feature = "builder")]
 mod conversions;
@@ -0,0 +1,82 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum AngleUnit {
+    #[default]
+    Radians,
+    Degrees,
+}
+
+impl AngleUnit {
+    pub fn to_radians(self, angle: f32) -> f32 {
+        match self {
+            Self::Radians => angle,
+            Self::Degrees => angle.to_radians(),
+        }
+    }
+}
+
+/// Interprets the data of a chart's series as `(theta, r)` pairs, with theta measured
+/// counter-clockwise from the positive x direction.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Polar {
+    /// Unit of the series' angles, also used for the angular labels.
+    pub unit: AngleUnit,
+    /// Number of evenly spaced angular grid lines.
+    pub angular_lines: usize,
+    /// Approximate number of radial grid circles.
+    pub radial_lines: usize,
+}
+
+impl std::default::Default for Polar {
+    fn default() -> Self {
+        Self {
+            unit: Default::default(),
+            angular_lines: 12,
+            radial_lines: 5,
+        }
+    }
+}
+
+impl Polar {
+    /// Converts a `(theta, r)` pair to cartesian coordinates.
+    pub fn to_cartesian(&self, (theta, r): (f32, f32)) -> (f32, f32) {
+        let theta = self.unit.to_radians(theta);
+        (r * theta.cos(), r * theta.sin())
+    }
+
+    /// Formats the angle of the `i`-th angular grid line, either in degrees or as a fraction of
+    /// π.
+    pub fn angle_label(&self, i: usize) -> String {
+        let n = self.angular_lines.max(1);
+        match self.unit {
+            AngleUnit::Degrees => {
+                let deg = 360.0 * i as f32 / n as f32;
+                format!("{}°", (deg * 100.0).round() / 100.0)
+            }
+            AngleUnit::Radians => {
+                // the angle is 2i/n π, reduced to lowest terms
+                let (num, den) = (2 * i, n);
+                let gcd = gcd(num, den);
+                match (num / gcd, den / gcd) {
+                    (0, _) => "0".to_string(),
+                    (1, 1) => "π".to_string(),
+                    (num, 1) => format!("{num}π"),
+                    (1, den) => format!("π/{den}"),
+                    (num, den) => format!("{num}π/{den}"),
+                }
+            }
+        }
+    }
+}
+
+fn gcd(a: usize, b: usize) -> usize {
+    if b == 0 {
+        a.max(1)
+    } else {
+        gcd(b, a % b)
+    }
+}
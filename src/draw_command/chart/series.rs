@@ -1,10 +1,21 @@
-use crate::{data_bound::DataBound2D, map_reduce::MapReduce};
+use crate::{
+    data_bound::{DataBound, DataBound2D},
+    stats::{linspace, sorted_finite, BoxStats},
+};
 
-use super::{series_style::SeriesStyle, series_ty::ChartSeriesType};
+use super::{
+    color_scale::ColorScale,
+    series_error::ErrorValue,
+    series_style::SeriesStyle,
+    series_ty::{ChartSeriesType, SampleGroup},
+};
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+/// The largest number of points [`ChartSeries::legend_avoid_points`] returns.
+const MAX_AVOID_POINTS: usize = 2000;
+
 #[derive(Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ChartSeries {
@@ -12,17 +23,221 @@ pub struct ChartSeries {
     pub data: Vec<(f32, f32)>,
     pub chart_series_type: ChartSeriesType,
     pub style: SeriesStyle,
+    /// Per-point x errors, matched to `data` by index. Points without an entry have no error.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub x_err: Vec<ErrorValue>,
+    /// Per-point y errors, matched to `data` by index. Points without an entry have no error.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub y_err: Vec<ErrorValue>,
+    /// Leaves the series out of the legend, even when it is named.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub exclude_from_legend: bool,
 }
 
 impl ChartSeries {
+    /// Returns the bounds of the point at index `i`, including its error extents.
+    fn point_bounds(&self, i: usize, (x, y): (f32, f32)) -> DataBound2D {
+        let x = self
+            .x_err
+            .get(i)
+            .map_or(DataBound::from(x), |err| err.bound(x));
+        let y = self
+            .y_err
+            .get(i)
+            .map_or(DataBound::from(y), |err| err.bound(y));
+        DataBound2D::from((x, y))
+    }
+
+    /// Returns the bounds of a distribution series. Each group occupies a unit wide slot
+    /// centered on its index, and `extent` returns the vertical range drawn for its samples.
+    fn groups_bounds(
+        groups: &[SampleGroup],
+        extent: impl Fn(&[f32]) -> Option<(f32, f32)>,
+    ) -> Option<DataBound2D> {
+        let y = groups
+            .iter()
+            .filter_map(|group| extent(&group.samples))
+            .map(DataBound::from)
+            .reduce(DataBound::extend_to_include)?;
+        let x = DataBound::from((-0.5, groups.len() as f32 - 0.5));
+        Some(DataBound2D::from((x, y)))
+    }
+
+    fn extent_bounds(x: (f32, f32), y: (f32, f32)) -> DataBound2D {
+        DataBound2D::from((
+            DataBound::from(x.0).extend_to_include(x.1),
+            DataBound::from(y.0).extend_to_include(y.1),
+        ))
+    }
+
     pub fn bounds(&self) -> Option<DataBound2D> {
+        match &self.chart_series_type {
+            ChartSeriesType::Box(b) => Self::groups_bounds(&b.groups, |samples| {
+                BoxStats::new(samples).map(|stats| stats.extent(b.show_outliers))
+            }),
+            ChartSeriesType::Violin(v) => Self::groups_bounds(&v.groups, |samples| {
+                let sorted = sorted_finite(samples);
+                Some((*sorted.first()?, *sorted.last()?))
+            }),
+            ChartSeriesType::Kde(k) => k
+                .curve()
+                .into_iter()
+                .map(DataBound2D::from)
+                .reduce(DataBound2D::extend_to_include_bound2d)
+                .map(|b| b.extend_to_include_bound(b.x, 0.0)),
+            ChartSeriesType::Ecdf(e) => e
+                .curve()
+                .into_iter()
+                .map(DataBound2D::from)
+                .reduce(DataBound2D::extend_to_include_bound2d),
+            ChartSeriesType::Heatmap(h) => Some(Self::extent_bounds(h.x_extent, h.y_extent)),
+            ChartSeriesType::Contour(c) => Some(Self::extent_bounds(c.x_extent, c.y_extent)),
+            ChartSeriesType::Density(d) => {
+                let ((x0, y0), (x1, y1)) = d.extent(&self.data)?;
+                Some(DataBound2D::from((
+                    DataBound::from((x0, x1)),
+                    DataBound::from((y0, y1)),
+                )))
+            }
+            ChartSeriesType::Candlestick(c) => {
+                let half = c.width() * 0.5;
+                c.candles
+                    .iter()
+                    .map(|candle| {
+                        DataBound2D::from((
+                            DataBound::from((candle.x - half, candle.x + half)),
+                            DataBound::from((candle.low, candle.high)),
+                        ))
+                    })
+                    .reduce(DataBound2D::extend_to_include_bound2d)
+            }
+            // automatically scaled arrows depend on the chart's size, and the chart fits them
+            ChartSeriesType::Quiver(q) => q.bounds(q.scale.unwrap_or(0.0)),
+            ChartSeriesType::Line(line) if line.filled => self
+                .points_bounds()
+                .map(|b| b.extend_to_include_bound(b.x, 0.0)),
+            ChartSeriesType::Stem(stem) => self
+                .points_bounds()
+                .map(|b| b.extend_to_include_bound(b.x, stem.baseline)),
+            _ => self.points_bounds(),
+        }
+    }
+
+    /// Returns points spread over what the series draws, in data coordinates, which a legend
+    /// placed automatically avoids covering. Series with many points are subsampled.
+    pub fn legend_avoid_points(&self) -> Vec<(f32, f32)> {
+        fn subsample(points: &[(f32, f32)]) -> Vec<(f32, f32)> {
+            let step = points.len().div_ceil(MAX_AVOID_POINTS).max(1);
+            points.iter().step_by(step).copied().collect()
+        }
+        // points along a vertical segment, and over a rectangle
+        let column = |x: f32, (lo, hi): (f32, f32)| linspace(lo, hi, 8).map(move |y| (x, y));
+        let area = |(x0, x1): (f32, f32), (y0, y1): (f32, f32)| {
+            linspace(y0, y1, 16)
+                .flat_map(move |y| linspace(x0, x1, 16).map(move |x| (x, y)))
+                .collect::<Vec<_>>()
+        };
+
+        let points = match &self.chart_series_type {
+            ChartSeriesType::Box(b) => b
+                .groups
+                .iter()
+                .enumerate()
+                .filter_map(|(i, group)| {
+                    let stats = BoxStats::new(&group.samples)?;
+                    Some(column(i as f32, stats.extent(b.show_outliers)))
+                })
+                .flatten()
+                .collect(),
+            ChartSeriesType::Violin(v) => v
+                .groups
+                .iter()
+                .enumerate()
+                .filter_map(|(i, group)| {
+                    let sorted = sorted_finite(&group.samples);
+                    Some(column(i as f32, (*sorted.first()?, *sorted.last()?)))
+                })
+                .flatten()
+                .collect(),
+            ChartSeriesType::Kde(k) => k.curve(),
+            ChartSeriesType::Ecdf(e) => e.curve(),
+            ChartSeriesType::Heatmap(h) => area(h.x_extent, h.y_extent),
+            ChartSeriesType::Contour(c) => area(c.x_extent, c.y_extent),
+            ChartSeriesType::Candlestick(c) => c
+                .candles
+                .iter()
+                .flat_map(|candle| column(candle.x, (candle.low, candle.high)))
+                .collect(),
+            ChartSeriesType::Quiver(q) => q.vectors.iter().map(|v| (v.x, v.y)).collect(),
+            // the cells of density series cover their points, and other series draw them
+            _ => return subsample(&self.data),
+        };
+        subsample(&points)
+    }
+
+    /// Returns the color scale of the series along with the range of values it maps onto the
+    /// colormap.
+    pub fn color_range(&self) -> Option<(&ColorScale, (f32, f32))> {
+        match &self.chart_series_type {
+            ChartSeriesType::Scatter(s) if !s.values.is_empty() => {
+                let range = s.color_scale.resolve(s.values.iter().copied())?;
+                Some((&s.color_scale, range))
+            }
+            ChartSeriesType::Heatmap(h) => {
+                let range = h.color_scale.resolve(h.values.iter().flatten().copied())?;
+                Some((&h.color_scale, range))
+            }
+            ChartSeriesType::Contour(c) => {
+                let range = c.color_scale.resolve(c.values.iter().flatten().copied())?;
+                Some((&c.color_scale, range))
+            }
+            ChartSeriesType::Density(d) => {
+                Some((&d.color_scale, d.color_range(&d.bin(&self.data))?))
+            }
+            ChartSeriesType::Quiver(q) => {
+                let scale = q.color_scale.as_ref()?;
+                let range = scale.resolve(q.vectors.iter().map(|v| v.magnitude()))?;
+                Some((scale, range))
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the series without its points that have a coordinate that is not finite, along
+    /// with their errors, sizes and values.
+    pub fn finite(&self) -> ChartSeries {
+        fn keep<T: Clone>(values: &[T], finite: &[bool]) -> Vec<T> {
+            values
+                .iter()
+                .zip(finite)
+                .filter(|(_, finite)| **finite)
+                .map(|(v, _)| v.clone())
+                .collect()
+        }
+
+        let finite = self
+            .data
+            .iter()
+            .map(|(x, y)| x.is_finite() && y.is_finite())
+            .collect::<Vec<_>>();
+        let mut series = self.clone();
+        series.data = keep(&self.data, &finite);
+        series.x_err = keep(&self.x_err, &finite);
+        series.y_err = keep(&self.y_err, &finite);
+        if let ChartSeriesType::Scatter(scatter) = &mut series.chart_series_type {
+            scatter.sizes = keep(&scatter.sizes, &finite);
+            scatter.values = keep(&scatter.values, &finite);
+        }
+        series
+    }
+
+    fn points_bounds(&self) -> Option<DataBound2D> {
         self.data
             .iter()
             .copied()
-            .map_reduce(
-                |item| DataBound2D::from(item),
-                |bounds2d, (x, y)| bounds2d.extend_to_include_bound(x, y),
-            )
-            .map(|bounds| bounds.into())
+            .enumerate()
+            .filter(|(_, (x, y))| x.is_finite() && y.is_finite())
+            .map(|(i, item)| self.point_bounds(i, item))
+            .reduce(DataBound2D::extend_to_include_bound2d)
     }
 }
@@ -1,11 +1,58 @@
-use crate::point_data::IntoData;
+use crate::{
+    colors::colormap::Colormap,
+    point_data::{IntoCategoricalData, IntoData, IntoSample, IntoSamples},
+    stats::{linspace, Bandwidth, Kernel},
+};
 use rgb::RGBA;
 
-use super::series::ChartSeries;
+use super::{
+    color_scale::{ColorScale, Colorbar},
+    series::ChartSeries,
+    series_error::ErrorValue,
+    series_ty::{
+        BinShape, ChartSeriesBoxConfig, ChartSeriesCandlestickConfig, ChartSeriesContourConfig,
+        ChartSeriesDensityConfig, ChartSeriesEcdfConfig, ChartSeriesHeatmapConfig,
+        ChartSeriesKdeConfig, ChartSeriesQuiverConfig, ChartSeriesType, ChartSeriesViolinConfig,
+        ContourLevels, CountScale, Ohlc, QuiverKey, SampleGroup, StepMode, Vector,
+    },
+    time::Timestamp,
+};
 
+fn sample_groups(
+    groups: impl IntoIterator<Item = (impl ToString, impl IntoSamples)>,
+) -> Vec<SampleGroup> {
+    groups
+        .into_iter()
+        .map(|(name, samples)| SampleGroup {
+            name: name.to_string(),
+            samples: samples.into_samples(),
+        })
+        .collect()
+}
+
+/// A setting of a specific series type, which does nothing for other types.
+type Setting = Box<dyn FnOnce(&mut ChartSeriesType)>;
+
+/// A setting of the color scale of series that map values to colors.
+type ScaleSetting = Box<dyn FnOnce(&mut ColorScale)>;
+
+/// Builds a series. The settings of specific series types, such as [`Self::bins`] for density
+/// series, are applied when the series is built, so they can be called before or after the
+/// method choosing the type. They are ignored by series of other types. The settings of the
+/// color scale, such as [`Self::colormap`], are applied after those of the type, which may
+/// enable it.
 pub struct ChartSeriesBuilder {
     pub s: ChartSeries,
     pub auto_color: bool,
+    /// Seconds since the Unix epoch from which the x values of time data are measured.
+    epoch: Option<i64>,
+    /// The x values of time data as seconds since the Unix epoch, kept at full precision until
+    /// they are measured from the chart's epoch.
+    times: Vec<f64>,
+    /// The categories indexed by the x values of categorical data.
+    categories: Vec<String>,
+    settings: Vec<Setting>,
+    scale_settings: Vec<ScaleSetting>,
 }
 
 impl std::default::Default for ChartSeriesBuilder {
@@ -16,16 +63,42 @@ impl std::default::Default for ChartSeriesBuilder {
                 data: Vec::new(),
                 chart_series_type: Default::default(),
                 style: Default::default(),
+                x_err: Vec::new(),
+                y_err: Vec::new(),
+                exclude_from_legend: false,
             },
             auto_color: true,
+            epoch: None,
+            times: Vec::new(),
+            categories: Vec::new(),
+            settings: Vec::new(),
+            scale_settings: Vec::new(),
         }
     }
 }
 
 impl ChartSeriesBuilder {
+    /// Records a setting of a specific series type, applied when the series is built.
+    fn setting(mut self, f: impl FnOnce(&mut ChartSeriesType) + 'static) -> Self {
+        self.settings.push(Box::new(f));
+        self
+    }
+
+    /// Records a setting of the color scale, applied when the series is built.
+    fn scale_setting(mut self, f: impl FnOnce(&mut ColorScale) + 'static) -> Self {
+        self.scale_settings.push(Box::new(f));
+        self
+    }
+
     pub fn series(mut self, series: impl Into<ChartSeries>) -> Self {
         self.s = series.into();
         self.auto_color = false;
+        self.epoch = None;
+        self.times.clear();
+        self.categories.clear();
+        // the series comes with its type fully configured
+        self.settings.clear();
+        self.scale_settings.clear();
         self
     }
 
@@ -34,8 +107,122 @@ impl ChartSeriesBuilder {
         self
     }
 
+    /// Leaves the series out of the legend.
+    pub fn exclude_from_legend(mut self) -> Self {
+        self.s.exclude_from_legend = true;
+        self
+    }
+
     pub fn data(mut self, data: impl IntoData) -> Self {
         self.s.data = data.into_data();
+        self.epoch = None;
+        self.times.clear();
+        self.categories.clear();
+        self
+    }
+
+    /// Sets time data, placing the points on a time x axis.
+    pub fn time_data(
+        mut self,
+        data: impl IntoIterator<Item = (impl Timestamp, impl IntoSample)>,
+    ) -> Self {
+        let (times, ys): (Vec<_>, Vec<_>) = data
+            .into_iter()
+            .map(|(t, y)| (t.unix_seconds(), y.into_sample()))
+            .unzip();
+        let epoch = times
+            .iter()
+            .copied()
+            .reduce(f64::min)
+            .unwrap_or(0.0)
+            .floor();
+        self.s.data = ys.into_iter().map(|y| (0.0, y)).collect();
+        self.times = times;
+        self.epoch = Some(epoch as i64);
+        self.rebase_time(epoch as i64);
+        self.categories.clear();
+        self
+    }
+
+    /// Measures the x values of time data from `epoch` instead.
+    pub fn rebase_time(&mut self, epoch: i64) {
+        if self.epoch.replace(epoch).is_some() {
+            // the offsets are taken in `f64`, so that only they are rounded to `f32`
+            for (p, t) in self.s.data.iter_mut().zip(&self.times) {
+                p.0 = (t - epoch as f64) as f32;
+            }
+        }
+    }
+
+    /// Sets values keyed by category names, placing the points on a categorical x axis.
+    pub fn categorical_data(mut self, data: impl IntoCategoricalData) -> Self {
+        let mut categories = Vec::<String>::new();
+        self.s.data = data
+            .into_categorical_data()
+            .into_iter()
+            .map(|(name, y)| {
+                let i = categories
+                    .iter()
+                    .position(|c| *c == name)
+                    .unwrap_or_else(|| {
+                        categories.push(name);
+                        categories.len() - 1
+                    });
+                (i as f32, y)
+            })
+            .collect();
+        self.categories = categories;
+        self.epoch = None;
+        self.times.clear();
+        self
+    }
+
+    /// Returns the epoch from which the x values of time data are measured, if the series has
+    /// time data.
+    pub fn epoch(&self) -> Option<i64> {
+        self.epoch
+    }
+
+    /// Returns the categories indexed by the x values of categorical data.
+    pub fn categories(&self) -> &[String] {
+        &self.categories
+    }
+
+    /// Places categorical data at the positions of its categories within `categories`, to
+    /// which the categories it is missing are appended.
+    pub fn remap_categories(&mut self, categories: &mut Vec<String>) {
+        let positions = self
+            .categories
+            .iter()
+            .map(|name| {
+                categories
+                    .iter()
+                    .position(|c| c == name)
+                    .unwrap_or_else(|| {
+                        categories.push(name.clone());
+                        categories.len() - 1
+                    })
+            })
+            .collect::<Vec<_>>();
+        for p in &mut self.s.data {
+            if let Some(i) = positions.get(p.0 as usize) {
+                p.0 = *i as f32;
+            }
+        }
+        self.categories = categories.clone();
+    }
+
+    /// Sets the x error of each point. Each error is either symmetric (`f32`) or asymmetric
+    /// (`(lower, upper)`).
+    pub fn x_err(mut self, err: impl IntoIterator<Item = impl Into<ErrorValue>>) -> Self {
+        self.s.x_err = err.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Sets the y error of each point. Each error is either symmetric (`f32`) or asymmetric
+    /// (`(lower, upper)`).
+    pub fn y_err(mut self, err: impl IntoIterator<Item = impl Into<ErrorValue>>) -> Self {
+        self.s.y_err = err.into_iter().map(Into::into).collect();
         self
     }
 
@@ -55,6 +242,18 @@ impl ChartSeriesBuilder {
         self
     }
 
+    /// Draws the line as a staircase, with the steps placed according to `mode`.
+    pub fn step(mut self, mode: StepMode) -> Self {
+        self.s.chart_series_type = self.s.chart_series_type.step(mode);
+        self
+    }
+
+    /// Fills the area under the line, or the area it encloses in polar charts.
+    pub fn fill(mut self) -> Self {
+        self.s.chart_series_type = self.s.chart_series_type.fill();
+        self
+    }
+
     pub fn scatter(mut self) -> Self {
         self.s.chart_series_type = self.s.chart_series_type.scatter();
         self
@@ -65,15 +264,439 @@ impl ChartSeriesBuilder {
         self
     }
 
+    /// Sets the size of each point of scatter series, drawn as markers whose areas scale with
+    /// the sizes.
+    pub fn sizes(self, sizes: impl IntoSamples) -> Self {
+        let sizes = sizes.into_samples();
+        self.setting(move |ty| {
+            if let ChartSeriesType::Scatter(s) = ty {
+                s.sizes = sizes;
+            }
+        })
+    }
+
+    /// Sets the marker radii in pixels of the smallest and largest sizes of scatter series.
+    pub fn radius_range(self, min: f32, max: f32) -> Self {
+        self.setting(move |ty| {
+            if let ChartSeriesType::Scatter(s) = ty {
+                s.radius_range = (min, max);
+            }
+        })
+    }
+
+    pub fn no_size_legend(self) -> Self {
+        self.setting(|ty| {
+            if let ChartSeriesType::Scatter(s) = ty {
+                s.size_legend = false;
+            }
+        })
+    }
+
+    /// Sets a value for each point of scatter series, mapped to its color through the
+    /// colormap.
+    pub fn values(self, values: impl IntoSamples) -> Self {
+        let values = values.into_samples();
+        self.setting(move |ty| {
+            if let ChartSeriesType::Scatter(s) = ty {
+                s.values = values;
+            }
+        })
+    }
+
+    /// Draws a vertical stem from the baseline to each point, with a marker at its tip.
+    pub fn stem(mut self) -> Self {
+        self.s.chart_series_type = self.s.chart_series_type.stem();
+        self
+    }
+
+    /// Draws a stem series with hollow markers at the tips of the stems.
+    pub fn stem_not_filled(mut self) -> Self {
+        self.s.chart_series_type = self.s.chart_series_type.stem_not_filled();
+        self
+    }
+
+    /// Sets the y value the stems of a stem series start from.
+    pub fn baseline(self, baseline: f32) -> Self {
+        self.setting(move |ty| {
+            if let ChartSeriesType::Stem(stem) = ty {
+                stem.baseline = baseline;
+            }
+        })
+    }
+
+    /// Draws each named group of raw samples as a box plot, one category per group.
+    pub fn box_plot(
+        mut self,
+        groups: impl IntoIterator<Item = (impl ToString, impl IntoSamples)>,
+    ) -> Self {
+        self.s.chart_series_type = ChartSeriesType::Box(ChartSeriesBoxConfig {
+            groups: sample_groups(groups),
+            ..Default::default()
+        });
+        self
+    }
+
+    /// Draws each named group of raw samples as a violin plot, one category per group.
+    pub fn violin(
+        mut self,
+        groups: impl IntoIterator<Item = (impl ToString, impl IntoSamples)>,
+    ) -> Self {
+        self.s.chart_series_type = ChartSeriesType::Violin(ChartSeriesViolinConfig {
+            groups: sample_groups(groups),
+            ..Default::default()
+        });
+        self
+    }
+
+    /// Draws a kernel density estimate of the raw samples as a smooth curve.
+    pub fn kde(mut self, samples: impl IntoSamples) -> Self {
+        self.s.chart_series_type = ChartSeriesType::Kde(ChartSeriesKdeConfig {
+            samples: samples.into_samples(),
+            ..Default::default()
+        });
+        self
+    }
+
+    pub fn kernel(self, kernel: Kernel) -> Self {
+        self.setting(move |ty| {
+            if let ChartSeriesType::Kde(k) = ty {
+                k.kernel = kernel;
+            }
+        })
+    }
+
+    /// Sets the bandwidth of density estimates, either as a rule of thumb or as a value.
+    pub fn bandwidth(self, bandwidth: impl Into<Bandwidth>) -> Self {
+        let bandwidth = bandwidth.into();
+        self.setting(move |ty| {
+            if let ChartSeriesType::Kde(k) = ty {
+                k.bandwidth = bandwidth;
+            }
+        })
+    }
+
+    /// Marks each sample of density estimates with a tick along the x axis.
+    pub fn rug(self) -> Self {
+        self.setting(|ty| {
+            if let ChartSeriesType::Kde(k) = ty {
+                k.rug = true;
+            }
+        })
+    }
+
+    /// Draws the empirical cumulative distribution of the raw samples.
+    pub fn ecdf(mut self, samples: impl IntoSamples) -> Self {
+        self.s.chart_series_type = ChartSeriesType::Ecdf(ChartSeriesEcdfConfig {
+            samples: samples.into_samples(),
+            ..Default::default()
+        });
+        self
+    }
+
+    /// Marks the given percentiles, between 0 and 100, of cumulative distributions.
+    pub fn percentiles(self, percentiles: impl IntoIterator<Item = f32>) -> Self {
+        let percentiles = percentiles.into_iter().collect();
+        self.setting(move |ty| {
+            if let ChartSeriesType::Ecdf(e) = ty {
+                e.percentiles = percentiles;
+            }
+        })
+    }
+
+    /// Draws a matrix of values as colored cells spanning `x_extent` and `y_extent` in data
+    /// coordinates. Each item of `rows` is a row of cells along x, starting from the bottom.
+    pub fn heatmap(
+        mut self,
+        rows: impl IntoIterator<Item = impl IntoSamples>,
+        x_extent: (f32, f32),
+        y_extent: (f32, f32),
+    ) -> Self {
+        self.s.chart_series_type = ChartSeriesType::Heatmap(ChartSeriesHeatmapConfig {
+            values: rows.into_iter().map(IntoSamples::into_samples).collect(),
+            x_extent,
+            y_extent,
+            ..Default::default()
+        });
+        self
+    }
+
+    /// Bins the series' points into cells of the given shape colored by how many points they
+    /// contain. Suited to point clouds too dense for a scatter plot.
+    pub fn density(mut self, shape: BinShape) -> Self {
+        self.s.chart_series_type = ChartSeriesType::Density(ChartSeriesDensityConfig {
+            shape,
+            ..Default::default()
+        });
+        self
+    }
+
+    /// Sets the number of cells across the horizontal extent of density series.
+    pub fn bins(self, bins: usize) -> Self {
+        self.setting(move |ty| {
+            if let ChartSeriesType::Density(d) = ty {
+                d.bins = bins;
+            }
+        })
+    }
+
+    /// Colors the cells of density series by the logarithm of their count.
+    pub fn log_counts(self) -> Self {
+        self.setting(|ty| {
+            if let ChartSeriesType::Density(d) = ty {
+                d.count_scale = CountScale::Log;
+                if let Some(colorbar) = &mut d.color_scale.colorbar {
+                    colorbar.label = "log10(count)".to_string();
+                }
+            }
+        })
+    }
+
+    /// Draws the iso-lines of a grid of values spanning `x_extent` and `y_extent` in data
+    /// coordinates. Each item of `rows` is a row of grid nodes along x, starting from the bottom.
+    pub fn contour(
+        mut self,
+        rows: impl IntoIterator<Item = impl IntoSamples>,
+        x_extent: (f32, f32),
+        y_extent: (f32, f32),
+    ) -> Self {
+        self.s.chart_series_type = ChartSeriesType::Contour(ChartSeriesContourConfig {
+            values: rows.into_iter().map(IntoSamples::into_samples).collect(),
+            x_extent,
+            y_extent,
+            ..Default::default()
+        });
+        self
+    }
+
+    /// Draws the iso-lines of `f(x, y)`, sampled on a grid of `cols` by `rows` nodes spanning
+    /// `x_extent` and `y_extent`.
+    pub fn contour_fn(
+        self,
+        f: impl Fn(f32, f32) -> f32,
+        x_extent: (f32, f32),
+        y_extent: (f32, f32),
+        (cols, rows): (usize, usize),
+    ) -> Self {
+        let (x0, x1) = x_extent;
+        let (y0, y1) = y_extent;
+        let values = linspace(y0, y1, rows)
+            .map(|y| linspace(x0, x1, cols).map(|x| f(x, y)).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        self.contour(values, x_extent, y_extent)
+    }
+
+    /// Sets the levels of contour series, either as a number of automatically placed levels or
+    /// as explicit values.
+    pub fn levels(self, levels: impl Into<ContourLevels>) -> Self {
+        let levels = levels.into();
+        self.setting(move |ty| {
+            if let ChartSeriesType::Contour(c) = ty {
+                c.levels = levels;
+            }
+        })
+    }
+
+    /// Fills the bands between the levels of contour series.
+    pub fn filled(self) -> Self {
+        self.setting(|ty| {
+            if let ChartSeriesType::Contour(c) = ty {
+                c.filled = true;
+            }
+        })
+    }
+
+    /// Labels each iso-line of contour series with its level.
+    pub fn level_labels(self) -> Self {
+        self.setting(|ty| {
+            if let ChartSeriesType::Contour(c) = ty {
+                c.labels = true;
+            }
+        })
+    }
+
+    /// Draws `(x, open, high, low, close)` candles.
+    pub fn candlestick(mut self, candles: impl IntoIterator<Item = impl Into<Ohlc>>) -> Self {
+        self.s.chart_series_type = ChartSeriesType::Candlestick(ChartSeriesCandlestickConfig {
+            candles: candles.into_iter().map(Into::into).collect(),
+            ..Default::default()
+        });
+        self
+    }
+
+    /// Sets the color of candlesticks that close above their open.
+    pub fn up_color(self, color: impl Into<RGBA<f32>>) -> Self {
+        let color = color.into();
+        self.setting(move |ty| {
+            if let ChartSeriesType::Candlestick(c) = ty {
+                c.up_color = color;
+            }
+        })
+    }
+
+    /// Sets the color of candlesticks that close at or below their open.
+    pub fn down_color(self, color: impl Into<RGBA<f32>>) -> Self {
+        let color = color.into();
+        self.setting(move |ty| {
+            if let ChartSeriesType::Candlestick(c) = ty {
+                c.down_color = color;
+            }
+        })
+    }
+
+    /// Sets the width of candlesticks in x units, instead of deriving it from their spacing.
+    pub fn candle_width(self, width: f32) -> Self {
+        self.setting(move |ty| {
+            if let ChartSeriesType::Candlestick(c) = ty {
+                c.width = Some(width);
+            }
+        })
+    }
+
+    /// Draws an arrow for each `(x, y, u, v)` vector.
+    pub fn quiver(mut self, vectors: impl IntoIterator<Item = impl Into<Vector>>) -> Self {
+        self.s.chart_series_type = ChartSeriesType::Quiver(ChartSeriesQuiverConfig {
+            vectors: vectors.into_iter().map(Into::into).collect(),
+            ..Default::default()
+        });
+        self
+    }
+
+    /// Sets the length in data units of a quiver arrow with a magnitude of one.
+    pub fn arrow_scale(self, scale: f32) -> Self {
+        self.setting(move |ty| {
+            if let ChartSeriesType::Quiver(q) = ty {
+                q.scale = Some(scale);
+            }
+        })
+    }
+
+    /// Colors the arrows of a quiver series by their magnitude.
+    pub fn color_by_magnitude(self) -> Self {
+        self.setting(|ty| {
+            if let ChartSeriesType::Quiver(q) = ty {
+                q.color_scale = Some(ColorScale {
+                    colorbar: Some(Colorbar {
+                        label: "magnitude".to_string(),
+                    }),
+                    ..Default::default()
+                });
+            }
+        })
+    }
+
+    /// Draws a reference arrow of the given magnitude next to a quiver series.
+    pub fn quiver_key(self, magnitude: f32, label: impl ToString) -> Self {
+        let label = label.to_string();
+        self.setting(move |ty| {
+            if let ChartSeriesType::Quiver(q) = ty {
+                q.key = Some(QuiverKey { magnitude, label });
+            }
+        })
+    }
+
+    /// Sets the colormap of series that map values to colors.
+    pub fn colormap(self, colormap: Colormap) -> Self {
+        self.scale_setting(move |scale| scale.colormap = colormap)
+    }
+
+    /// Sets the value mapped to the start of the colormap.
+    pub fn vmin(self, vmin: f32) -> Self {
+        self.scale_setting(move |scale| scale.vmin = Some(vmin))
+    }
+
+    /// Sets the value mapped to the end of the colormap.
+    pub fn vmax(self, vmax: f32) -> Self {
+        self.scale_setting(move |scale| scale.vmax = Some(vmax))
+    }
+
+    pub fn colorbar(self, label: impl ToString) -> Self {
+        let label = label.to_string();
+        self.scale_setting(move |scale| scale.colorbar = Some(Colorbar { label }))
+    }
+
+    pub fn no_colorbar(self) -> Self {
+        self.scale_setting(|scale| scale.colorbar = None)
+    }
+
     pub fn build(self) -> ChartSeries {
-        self.s
+        let mut s = self.s;
+        for setting in self.settings {
+            setting(&mut s.chart_series_type);
+        }
+        if let Some(scale) = s.chart_series_type.color_scale_mut() {
+            for setting in self.scale_settings {
+                setting(scale);
+            }
+        }
+        s
     }
 
     pub fn build_with_color(self, color: impl Into<RGBA<f32>>) -> ChartSeries {
-        let mut s = self.s;
-        if self.auto_color {
+        let auto_color = self.auto_color;
+        let mut s = self.build();
+        if auto_color {
             s.style.color = color.into();
         }
         s
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::draw_command::chart::chart_builder::ChartBuilder;
+
+    const DAY: i64 = 86400;
+
+    #[test]
+    fn later_data_replaces_time_data() {
+        let times = [(19723 * DAY, 1.0), (19724 * DAY, 2.0)];
+        let b = ChartSeriesBuilder::default()
+            .time_data(times)
+            .data([(5.0, 1.0)]);
+        assert_eq!(b.epoch(), None);
+
+        let chart = ChartBuilder::default()
+            .add_series_l_with(|b| {
+                b.time_data(times)
+                    .categorical_data([("a", 1.0), ("b", 2.0)])
+            })
+            .build();
+        assert!(chart.x_axis.time.is_none());
+        assert_eq!(chart.series_l[0].data, vec![(0.0, 1.0), (1.0, 2.0)]);
+    }
+
+    #[test]
+    fn later_data_replaces_categories() {
+        let chart = ChartBuilder::default()
+            .sort_categories()
+            .add_series_l_with(|b| {
+                b.categorical_data([("b", 1.0), ("a", 2.0)])
+                    .data([(5.0, 1.0)])
+            })
+            .add_series_l_with(|b| {
+                b.categorical_data([("b", 1.0)])
+                    .series(ChartSeriesBuilder::default().data([(7.0, 3.0)]).build())
+            })
+            .build();
+        assert!(chart.x_axis.categories.is_empty());
+        assert_eq!(chart.series_l[0].data, vec![(5.0, 1.0)]);
+        assert_eq!(chart.series_l[1].data, vec![(7.0, 3.0)]);
+    }
+
+    #[test]
+    fn series_discards_pending_settings() {
+        let series = ChartSeriesBuilder::default()
+            .bins(5)
+            .series(
+                ChartSeriesBuilder::default()
+                    .density(BinShape::Rect)
+                    .build(),
+            )
+            .build();
+        match series.chart_series_type {
+            ChartSeriesType::Density(d) => assert_eq!(d.bins, 30),
+            _ => panic!("expected a density series"),
+        }
+    }
+}
@@ -0,0 +1,42 @@
+use crate::data_bound::DataBound;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The error of a single data point, stored as the distances below and above the point.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ErrorValue {
+    pub lower: f32,
+    pub upper: f32,
+}
+
+impl From<f32> for ErrorValue {
+    fn from(value: f32) -> Self {
+        Self::symmetric(value)
+    }
+}
+
+impl From<(f32, f32)> for ErrorValue {
+    fn from((lower, upper): (f32, f32)) -> Self {
+        Self::asymmetric(lower, upper)
+    }
+}
+
+impl ErrorValue {
+    pub fn symmetric(err: f32) -> Self {
+        Self {
+            lower: err,
+            upper: err,
+        }
+    }
+
+    pub fn asymmetric(lower: f32, upper: f32) -> Self {
+        Self { lower, upper }
+    }
+
+    /// Returns the range covered by the error around `value`.
+    pub fn bound(self, value: f32) -> DataBound {
+        DataBound::from((value - self.lower, value + self.upper))
+    }
+}
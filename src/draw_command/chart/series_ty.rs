@@ -1,35 +1,659 @@
+use crate::{
+    binning::{hex_bins, hex_bins_extent, rect_bins, rect_bins_extent, Bin},
+    contour::{auto_levels, Grid},
+    data_bound::DataBound2D,
+    stats::{kde, linspace, sorted_finite, Bandwidth, Kernel},
+};
+
+use rgb::RGBA;
+
+use super::color_scale::{ColorScale, Colorbar};
+
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+/// Where the vertical segment of a step line is placed relative to each point.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum StepMode {
+    /// The step happens at the start of the interval: `y[i]` holds on `(x[i - 1], x[i]]`.
+    Pre,
+    /// The step happens at the end of the interval: `y[i]` holds on `[x[i], x[i + 1])`.
+    Post,
+    /// The step happens halfway between consecutive points.
+    Mid,
+}
+
+impl StepMode {
+    /// Converts the points of a line into the corners of the staircase that connects them.
+    pub fn apply(self, data: &[(f32, f32)]) -> Vec<(f32, f32)> {
+        let mut out = Vec::with_capacity(data.len() * 2);
+        out.extend(data.first());
+        for w in data.windows(2) {
+            let ((x0, y0), (x1, y1)) = (w[0], w[1]);
+            match self {
+                Self::Pre => out.push((x0, y1)),
+                Self::Post => out.push((x1, y0)),
+                Self::Mid => {
+                    let xm = (x0 + x1) * 0.5;
+                    out.extend([(xm, y0), (xm, y1)]);
+                }
+            }
+            out.push((x1, y1));
+        }
+        out
+    }
+}
+
 #[derive(Debug, Clone, Copy)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ChartSeriesLineConfig {
     pub dashed: bool,
+    /// Draw a staircase instead of interpolating linearly between points.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub step: Option<StepMode>,
+    /// Fill the area between the line and zero, or the area enclosed by the line in polar
+    /// charts.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub filled: bool,
 }
 
 impl std::default::Default for ChartSeriesLineConfig {
     fn default() -> Self {
-        Self { dashed: false }
+        Self {
+            dashed: false,
+            step: None,
+            filled: false,
+        }
     }
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 pub struct ChartSeriesScatterConfig {
     pub filled: bool,
+    /// Per-point sizes, matched to the data by index. The marker areas scale linearly with the
+    /// sizes, between the radii of `radius_range`.
+    pub sizes: Vec<f32>,
+    /// Marker radii in pixels of the smallest and largest sizes.
+    pub radius_range: (f32, f32),
+    /// Shows a legend mapping marker sizes to values.
+    pub size_legend: bool,
+    /// Per-point values, matched to the data by index, mapped to colors through `color_scale`.
+    pub values: Vec<f32>,
+    pub color_scale: ColorScale,
 }
 
 impl std::default::Default for ChartSeriesScatterConfig {
     fn default() -> Self {
-        Self { filled: true }
+        Self {
+            filled: true,
+            sizes: Vec::new(),
+            radius_range: (3.0, 15.0),
+            size_legend: true,
+            values: Vec::new(),
+            color_scale: Default::default(),
+        }
+    }
+}
+
+impl ChartSeriesScatterConfig {
+    /// Marker radius of points without a size.
+    pub const RADIUS: f32 = 2.0;
+
+    /// Returns the finite minimum and maximum of the sizes.
+    pub fn size_extent(&self) -> Option<(f32, f32)> {
+        self.sizes
+            .iter()
+            .copied()
+            .filter(|v| v.is_finite())
+            .fold(None, |acc, v| {
+                Some(acc.map_or((v, v), |(lo, hi): (f32, f32)| (lo.min(v), hi.max(v))))
+            })
+    }
+
+    /// Returns the marker radius of the given size, with sizes spanning `extent`.
+    pub fn radius(&self, size: f32, (lo, hi): (f32, f32)) -> f32 {
+        let (r0, r1) = self.radius_range;
+        let t = if hi > lo {
+            ((size - lo) / (hi - lo)).clamp(0.0, 1.0)
+        } else {
+            0.5
+        };
+        (r0 * r0 + (r1 * r1 - r0 * r0) * t).sqrt()
+    }
+
+    /// Returns the marker radius of the point at index `i`.
+    pub fn point_radius(&self, i: usize, extent: Option<(f32, f32)>) -> f32 {
+        match (self.sizes.get(i), extent) {
+            (Some(size), Some(extent)) if size.is_finite() => self.radius(*size, extent),
+            _ => Self::RADIUS,
+        }
     }
 }
 
 #[derive(Debug, Clone, Copy)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ChartSeriesStemConfig {
+    /// The y value the stems start from.
+    pub baseline: f32,
+    /// Fills the markers at the tips of the stems.
+    pub filled: bool,
+}
+
+impl std::default::Default for ChartSeriesStemConfig {
+    fn default() -> Self {
+        Self {
+            baseline: 0.0,
+            filled: true,
+        }
+    }
+}
+
+/// A named group of raw samples, drawn as a single category along the x axis.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SampleGroup {
+    pub name: String,
+    pub samples: Vec<f32>,
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ChartSeriesBoxConfig {
+    /// The groups, placed at x = 0, 1, 2, ...
+    pub groups: Vec<SampleGroup>,
+    /// Width of each box in x units.
+    pub width: f32,
+    pub show_outliers: bool,
+}
+
+impl std::default::Default for ChartSeriesBoxConfig {
+    fn default() -> Self {
+        Self {
+            groups: Vec::new(),
+            width: 0.6,
+            show_outliers: true,
+        }
+    }
+}
+
+/// A smooth density curve estimated from raw samples.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ChartSeriesKdeConfig {
+    pub samples: Vec<f32>,
+    pub kernel: Kernel,
+    pub bandwidth: Bandwidth,
+    /// Number of points the curve is evaluated at.
+    pub resolution: usize,
+    /// Marks each sample with a tick along the x axis.
+    pub rug: bool,
+}
+
+impl std::default::Default for ChartSeriesKdeConfig {
+    fn default() -> Self {
+        Self {
+            samples: Vec::new(),
+            kernel: Default::default(),
+            bandwidth: Default::default(),
+            resolution: 200,
+            rug: false,
+        }
+    }
+}
+
+impl ChartSeriesKdeConfig {
+    /// Evaluates the density over the samples' range, widened by the kernel's support.
+    pub fn curve(&self) -> Vec<(f32, f32)> {
+        let sorted = sorted_finite(&self.samples);
+        let (Some(lo), Some(hi)) = (sorted.first(), sorted.last()) else {
+            return Vec::new();
+        };
+        let Some(bandwidth) = self.bandwidth.resolve(&sorted) else {
+            return Vec::new();
+        };
+
+        let pad = bandwidth * self.kernel.support();
+        linspace(lo - pad, hi + pad, self.resolution)
+            .map(|x| (x, kde(&sorted, self.kernel, bandwidth, x)))
+            .collect()
+    }
+}
+
+/// The empirical cumulative distribution of raw samples, drawn as a step function.
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ChartSeriesEcdfConfig {
+    pub samples: Vec<f32>,
+    /// Percentiles, between 0 and 100, marked with a labeled vertical line.
+    pub percentiles: Vec<f32>,
+}
+
+impl ChartSeriesEcdfConfig {
+    /// Returns the steps of the distribution, starting from zero at the smallest sample.
+    pub fn curve(&self) -> Vec<(f32, f32)> {
+        let sorted = sorted_finite(&self.samples);
+        let Some(first) = sorted.first() else {
+            return Vec::new();
+        };
+
+        let n = sorted.len() as f32;
+        let points = std::iter::once((*first, 0.0))
+            .chain(
+                sorted
+                    .iter()
+                    .enumerate()
+                    .map(|(i, x)| (*x, (i + 1) as f32 / n)),
+            )
+            .collect::<Vec<_>>();
+        StepMode::Pre.apply(&points)
+    }
+
+    /// Returns the smallest sample at which the distribution reaches the `p`-th percentile.
+    pub fn percentile(&self, p: f32) -> Option<f32> {
+        let sorted = sorted_finite(&self.samples);
+        let rank = (p.clamp(0.0, 100.0) / 100.0 * sorted.len() as f32).ceil() as usize;
+        sorted.get(rank.saturating_sub(1)).copied()
+    }
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ChartSeriesViolinConfig {
+    /// The groups, placed at x = 0, 1, 2, ...
+    pub groups: Vec<SampleGroup>,
+    /// Width of each violin at its widest point in x units.
+    pub width: f32,
+    /// Draw the quartile range and the median inside each violin.
+    pub show_box: bool,
+}
+
+impl std::default::Default for ChartSeriesViolinConfig {
+    fn default() -> Self {
+        Self {
+            groups: Vec::new(),
+            width: 0.8,
+            show_box: true,
+        }
+    }
+}
+
+/// A matrix of values drawn as colored cells covering `x_extent` by `y_extent` in data
+/// coordinates. `values[row][col]` is the cell at column `col` along x and row `row` along y, with
+/// the first row at the start of `y_extent`.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ChartSeriesHeatmapConfig {
+    pub values: Vec<Vec<f32>>,
+    pub x_extent: (f32, f32),
+    pub y_extent: (f32, f32),
+    pub color_scale: ColorScale,
+}
+
+impl std::default::Default for ChartSeriesHeatmapConfig {
+    fn default() -> Self {
+        Self {
+            values: Vec::new(),
+            x_extent: (0.0, 1.0),
+            y_extent: (0.0, 1.0),
+            color_scale: Default::default(),
+        }
+    }
+}
+
+impl ChartSeriesHeatmapConfig {
+    pub fn rows(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn cols(&self) -> usize {
+        self.values.iter().map(Vec::len).max().unwrap_or(0)
+    }
+
+    /// Returns the data space rectangle covered by the cell at `(row, col)`.
+    pub fn cell(&self, row: usize, col: usize) -> [(f32, f32); 2] {
+        let (x0, x1) = self.x_extent;
+        let (y0, y1) = self.y_extent;
+        let w = (x1 - x0) / self.cols() as f32;
+        let h = (y1 - y0) / self.rows() as f32;
+        let (x, y) = (x0 + w * col as f32, y0 + h * row as f32);
+        [(x, y), (x + w, y + h)]
+    }
+}
+
+/// The shape of the cells of a density series.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum BinShape {
+    #[default]
+    Hex,
+    Rect,
+}
+
+/// How the number of points in a cell is mapped onto the colormap.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum CountScale {
+    #[default]
+    Linear,
+    /// Colors cells by the base 10 logarithm of their count.
+    Log,
+}
+
+impl CountScale {
+    pub fn apply(self, count: usize) -> f32 {
+        match self {
+            Self::Linear => count as f32,
+            Self::Log => (count as f32).log10(),
+        }
+    }
+}
+
+/// Bins the series' points into cells colored by the number of points they contain.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ChartSeriesDensityConfig {
+    pub shape: BinShape,
+    /// Number of cells across the horizontal extent of the points, at least one.
+    pub bins: usize,
+    pub count_scale: CountScale,
+    pub color_scale: ColorScale,
+}
+
+impl std::default::Default for ChartSeriesDensityConfig {
+    fn default() -> Self {
+        Self {
+            shape: Default::default(),
+            bins: 30,
+            count_scale: Default::default(),
+            color_scale: ColorScale {
+                colorbar: Some(Colorbar {
+                    label: "count".to_string(),
+                }),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+impl ChartSeriesDensityConfig {
+    pub fn bin(&self, points: &[(f32, f32)]) -> Vec<Bin> {
+        match self.shape {
+            BinShape::Hex => hex_bins(points, self.bins),
+            BinShape::Rect => rect_bins(points, self.bins),
+        }
+    }
+
+    /// Returns the minimum and maximum corners of the cells [`Self::bin`] would return, without
+    /// counting the points.
+    pub fn extent(&self, points: &[(f32, f32)]) -> Option<((f32, f32), (f32, f32))> {
+        match self.shape {
+            BinShape::Hex => hex_bins_extent(points, self.bins),
+            BinShape::Rect => rect_bins_extent(points, self.bins),
+        }
+    }
+
+    /// Returns the range of the scaled counts of `bins` the colormap spans.
+    pub fn color_range(&self, bins: &[Bin]) -> Option<(f32, f32)> {
+        let counts = bins.iter().map(|bin| self.count_scale.apply(bin.count));
+        self.color_scale.resolve(counts)
+    }
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ContourLevels {
+    /// The given number of levels, evenly spaced between the minimum and maximum values.
+    Auto(usize),
+    Values(Vec<f32>),
+}
+
+impl std::default::Default for ContourLevels {
+    fn default() -> Self {
+        Self::Auto(8)
+    }
+}
+
+impl From<usize> for ContourLevels {
+    fn from(value: usize) -> Self {
+        Self::Auto(value)
+    }
+}
+
+impl From<Vec<f32>> for ContourLevels {
+    fn from(value: Vec<f32>) -> Self {
+        Self::Values(value)
+    }
+}
+
+impl<const N: usize> From<[f32; N]> for ContourLevels {
+    fn from(value: [f32; N]) -> Self {
+        Self::Values(value.to_vec())
+    }
+}
+
+/// Iso-lines of a scalar field sampled on a regular grid spanning `x_extent` by `y_extent`.
+/// `values[row][col]` is the value at the grid node `col` along x and `row` along y.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ChartSeriesContourConfig {
+    pub values: Vec<Vec<f32>>,
+    pub x_extent: (f32, f32),
+    pub y_extent: (f32, f32),
+    pub levels: ContourLevels,
+    /// Fill the bands between consecutive levels.
+    pub filled: bool,
+    /// Label each iso-line with its level.
+    pub labels: bool,
+    /// Colors the lines, or the bands when filled, by their level.
+    pub color_scale: ColorScale,
+}
+
+impl std::default::Default for ChartSeriesContourConfig {
+    fn default() -> Self {
+        Self {
+            values: Vec::new(),
+            x_extent: (0.0, 1.0),
+            y_extent: (0.0, 1.0),
+            levels: Default::default(),
+            filled: false,
+            labels: false,
+            color_scale: ColorScale {
+                colorbar: None,
+                ..Default::default()
+            },
+        }
+    }
+}
+
+impl ChartSeriesContourConfig {
+    pub fn grid(&self) -> Grid<'_> {
+        Grid {
+            values: &self.values,
+            x_extent: self.x_extent,
+            y_extent: self.y_extent,
+        }
+    }
+
+    /// Returns the sorted levels at which iso-lines are drawn.
+    pub fn levels(&self) -> Vec<f32> {
+        let mut levels = match &self.levels {
+            ContourLevels::Auto(n) => self
+                .grid()
+                .range()
+                .map(|range| auto_levels(range, *n))
+                .unwrap_or_default(),
+            ContourLevels::Values(levels) => levels.clone(),
+        };
+        levels.sort_by(f32::total_cmp);
+        levels
+    }
+}
+
+/// A single candle: the open, high, low and close values at `x`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Ohlc {
+    pub x: f32,
+    pub open: f32,
+    pub high: f32,
+    pub low: f32,
+    pub close: f32,
+}
+
+impl From<(f32, f32, f32, f32, f32)> for Ohlc {
+    fn from((x, open, high, low, close): (f32, f32, f32, f32, f32)) -> Self {
+        Self {
+            x,
+            open,
+            high,
+            low,
+            close,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ChartSeriesCandlestickConfig {
+    pub candles: Vec<Ohlc>,
+    /// Color of candles that close above their open.
+    pub up_color: RGBA<f32>,
+    /// Color of candles that close at or below their open.
+    pub down_color: RGBA<f32>,
+    /// Width of each candle in x units. When not set, it is derived from the spacing of the
+    /// candles.
+    pub width: Option<f32>,
+}
+
+impl std::default::Default for ChartSeriesCandlestickConfig {
+    fn default() -> Self {
+        Self {
+            candles: Vec::new(),
+            up_color: RGBA::new(0.17, 0.63, 0.17, 1.0),
+            down_color: RGBA::new(0.84, 0.15, 0.16, 1.0),
+            width: None,
+        }
+    }
+}
+
+impl ChartSeriesCandlestickConfig {
+    /// Returns the width of each candle in x units.
+    pub fn width(&self) -> f32 {
+        if let Some(width) = self.width {
+            return width;
+        }
+
+        let mut xs = self.candles.iter().map(|c| c.x).collect::<Vec<_>>();
+        xs.sort_by(f32::total_cmp);
+        let spacing = xs
+            .windows(2)
+            .map(|w| w[1] - w[0])
+            .filter(|d| *d > 0.0)
+            .fold(f32::MAX, f32::min);
+        if spacing == f32::MAX {
+            1.0
+        } else {
+            spacing * 0.6
+        }
+    }
+}
+
+/// A vector `(u, v)` placed at `(x, y)`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Vector {
+    pub x: f32,
+    pub y: f32,
+    pub u: f32,
+    pub v: f32,
+}
+
+impl From<(f32, f32, f32, f32)> for Vector {
+    fn from((x, y, u, v): (f32, f32, f32, f32)) -> Self {
+        Self { x, y, u, v }
+    }
+}
+
+impl Vector {
+    pub fn magnitude(&self) -> f32 {
+        self.u.hypot(self.v)
+    }
+}
+
+/// A reference arrow of a known magnitude, drawn in the corner of the chart.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct QuiverKey {
+    pub magnitude: f32,
+    pub label: String,
+}
+
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ChartSeriesQuiverConfig {
+    pub vectors: Vec<Vector>,
+    /// Length in data units of an arrow with a magnitude of one. When not set, the arrows are
+    /// scaled so that the longest one spans a tenth of the chart's smaller side, measured
+    /// before fitting the arrows.
+    pub scale: Option<f32>,
+    /// Colors the arrows by their magnitude.
+    pub color_scale: Option<ColorScale>,
+    pub key: Option<QuiverKey>,
+}
+
+impl ChartSeriesQuiverConfig {
+    /// Returns the length in data units of an arrow with a magnitude of one. Automatically
+    /// scaled arrows are relative to `size`, the smaller of the width and height of the chart,
+    /// see [`Chart::arrow_size`](super::chart::Chart::arrow_size).
+    pub fn scale(&self, size: f32) -> f32 {
+        if let Some(scale) = self.scale {
+            return scale;
+        }
+
+        let max = self
+            .vectors
+            .iter()
+            .map(Vector::magnitude)
+            .filter(|m| m.is_finite())
+            .fold(0.0, f32::max);
+
+        if max > 0.0 {
+            size * 0.1 / max
+        } else {
+            1.0
+        }
+    }
+
+    /// Returns the bounds of the arrows, drawn with the given `scale`.
+    pub fn bounds(&self, scale: f32) -> Option<DataBound2D> {
+        self.vectors
+            .iter()
+            .map(|v| {
+                DataBound2D::from((v.x, v.y))
+                    .extend_to_include_bound(v.x + v.u * scale, v.y + v.v * scale)
+            })
+            .reduce(DataBound2D::extend_to_include_bound2d)
+    }
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum ChartSeriesType {
     Line(ChartSeriesLineConfig),
     Scatter(ChartSeriesScatterConfig),
+    Stem(ChartSeriesStemConfig),
+    Box(ChartSeriesBoxConfig),
+    Violin(ChartSeriesViolinConfig),
+    Kde(ChartSeriesKdeConfig),
+    Ecdf(ChartSeriesEcdfConfig),
+    Heatmap(ChartSeriesHeatmapConfig),
+    Density(ChartSeriesDensityConfig),
+    Contour(ChartSeriesContourConfig),
+    Candlestick(ChartSeriesCandlestickConfig),
+    Quiver(ChartSeriesQuiverConfig),
 }
 
 impl std::default::Default for ChartSeriesType {
@@ -52,11 +676,75 @@ impl ChartSeriesType {
         Self::Line(line)
     }
 
+    pub fn step(self, mode: StepMode) -> Self {
+        let mut line = self.get_line().unwrap_or_default();
+        line.step = Some(mode);
+        Self::Line(line)
+    }
+
+    pub fn fill(self) -> Self {
+        let mut line = self.get_line().unwrap_or_default();
+        line.filled = true;
+        Self::Line(line)
+    }
+
     pub fn scatter(self) -> Self {
         Self::Scatter(Default::default())
     }
 
     pub fn scatter_not_filled(self) -> Self {
-        Self::Scatter(ChartSeriesScatterConfig { filled: false })
+        Self::Scatter(ChartSeriesScatterConfig {
+            filled: false,
+            ..Default::default()
+        })
+    }
+
+    pub fn stem(self) -> Self {
+        Self::Stem(Default::default())
+    }
+
+    pub fn stem_not_filled(self) -> Self {
+        Self::Stem(ChartSeriesStemConfig {
+            filled: false,
+            ..Default::default()
+        })
+    }
+
+    /// Returns whether polar charts draw the series, by converting its points from `(theta, r)`
+    /// pairs. Only line and scatter series are drawn, as other types place their points on a
+    /// grid, bins or a baseline, or compute them from samples.
+    pub fn supports_polar(&self) -> bool {
+        matches!(self, Self::Line(_) | Self::Scatter(_))
+    }
+
+    /// Returns the sample groups of distribution series (box and violin plots).
+    pub fn groups(&self) -> Option<&[SampleGroup]> {
+        match self {
+            Self::Box(b) => Some(&b.groups),
+            Self::Violin(v) => Some(&v.groups),
+            _ => None,
+        }
+    }
+
+    pub fn color_scale(&self) -> Option<&ColorScale> {
+        match self {
+            Self::Scatter(s) => Some(&s.color_scale),
+            Self::Heatmap(h) => Some(&h.color_scale),
+            Self::Density(d) => Some(&d.color_scale),
+            Self::Contour(c) => Some(&c.color_scale),
+            Self::Quiver(q) => q.color_scale.as_ref(),
+            _ => None,
+        }
+    }
+
+    pub fn color_scale_mut(&mut self) -> Option<&mut ColorScale> {
+        match self {
+            Self::Scatter(s) => Some(&mut s.color_scale),
+            Self::Heatmap(h) => Some(&mut h.color_scale),
+            Self::Density(d) => Some(&mut d.color_scale),
+            Self::Contour(c) => Some(&mut c.color_scale),
+            Self::Quiver(q) => q.color_scale.as_mut(),
+            _ => None,
+        }
     }
 }
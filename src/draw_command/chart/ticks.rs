@@ -0,0 +1,275 @@
+use std::sync::Arc;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Formats a value with at most `decimals` decimals, without trailing zeros.
+fn trimmed(value: f32, decimals: usize) -> String {
+    let s = format!("{value:.decimals$}");
+    if s.contains('.') {
+        s.trim_end_matches('0').trim_end_matches('.').to_string()
+    } else {
+        s
+    }
+}
+
+/// Scales a value down by the largest of `units` it reaches, each `step` times the previous one,
+/// and appends the unit's suffix.
+fn with_prefix(value: f32, step: f32, units: &[&str], separator: &str) -> String {
+    let mut scaled = value;
+    let mut unit = 0;
+    while scaled.abs() >= step && unit + 1 < units.len() {
+        scaled /= step;
+        unit += 1;
+    }
+    format!("{}{separator}{}", trimmed(scaled, 1), units[unit])
+}
+
+/// A closure formatting tick labels. It is not serialized.
+#[derive(Clone)]
+pub struct TickFormatter(pub Arc<dyn Fn(f32) -> String + Send + Sync>);
+
+impl std::fmt::Debug for TickFormatter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("TickFormatter")
+    }
+}
+
+/// How the values of ticks are written.
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum TickFormat {
+    /// Chooses a format from the axis' scale and range.
+    #[default]
+    Auto,
+    /// A fixed number of decimals, such as `1.50`.
+    Fixed(usize),
+    /// Scientific notation with a fixed number of decimals, such as `1.50e3`.
+    Scientific(usize),
+    /// Metric prefixes, such as `1.5k` or `20M`.
+    Si,
+    /// Fractions as percentages, such as `25%` for `0.25`.
+    Percent,
+    /// Bytes with binary prefixes, such as `1.2 GiB`.
+    Bytes,
+    /// Seconds as a duration, such as `1h 30m` or `250ms`.
+    Duration,
+    /// Formats with a closure. Charts are serialized with [`TickFormat::Auto`] in its place.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    Custom(TickFormatter),
+}
+
+impl TickFormat {
+    /// Formats `value`, or returns `None` for [`TickFormat::Auto`].
+    pub fn format(&self, value: f32) -> Option<String> {
+        let s = match self {
+            Self::Auto => return None,
+            Self::Fixed(decimals) => format!("{value:.decimals$}"),
+            Self::Scientific(decimals) => format!("{value:.decimals$e}"),
+            Self::Si => {
+                if value != 0.0 && value.abs() < 1.0 {
+                    let mut scaled = value;
+                    let mut unit = 0;
+                    let units = ["", "m", "µ", "n", "p"];
+                    while scaled.abs() < 1.0 && unit + 1 < units.len() {
+                        scaled *= 1000.0;
+                        unit += 1;
+                    }
+                    format!("{}{}", trimmed(scaled, 1), units[unit])
+                } else {
+                    with_prefix(value, 1000.0, &["", "k", "M", "G", "T", "P"], "")
+                }
+            }
+            Self::Percent => format!("{}%", trimmed(value * 100.0, 1)),
+            Self::Bytes => with_prefix(
+                value,
+                1024.0,
+                &["B", "KiB", "MiB", "GiB", "TiB", "PiB"],
+                " ",
+            ),
+            Self::Duration => Self::duration(value),
+            Self::Custom(f) => (f.0)(value),
+        };
+        Some(s)
+    }
+
+    /// Formats seconds with their two largest units, such as `2d 4h`. The value is rounded to
+    /// the smaller unit, carrying into the larger one, so that `7199` is `2h`.
+    fn duration(seconds: f32) -> String {
+        let sign = if seconds < 0.0 { "-" } else { "" };
+        let s = seconds.abs();
+        if s == 0.0 {
+            return "0s".to_string();
+        }
+        // values that round up to a second or a minute are written in the larger unit
+        if (s * 1000.0 * 10.0).round() < 1000.0 * 10.0 {
+            return format!("{sign}{}ms", trimmed(s * 1000.0, 1));
+        }
+        if (s * 10.0).round() < 60.0 * 10.0 {
+            return format!("{sign}{}s", trimmed(s, 1));
+        }
+
+        let units = [(86400.0, "d"), (3600.0, "h"), (60.0, "m"), (1.0, "s")];
+        let minor = |i: usize| units[(i + 1).min(3)].0;
+        // the largest unit the value reaches once rounded to the unit below it
+        let i = (0..units.len())
+            .find(|&i| (s / minor(i)).round() * minor(i) >= units[i].0)
+            .unwrap_or(3);
+        let (major, major_name) = units[i];
+        let (minor, minor_name) = units[(i + 1).min(3)];
+        let total = (s / minor).round();
+        let per_major = (major / minor).round();
+        let whole = (total / per_major).floor();
+        let rest = total - whole * per_major;
+        if rest == 0.0 {
+            format!("{sign}{whole}{major_name}")
+        } else {
+            format!("{sign}{whole}{major_name} {rest}{minor_name}")
+        }
+    }
+}
+
+/// A tick placed at a fixed position, optionally with its own label.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Tick {
+    pub value: f32,
+    pub label: Option<String>,
+}
+
+impl From<f32> for Tick {
+    fn from(value: f32) -> Self {
+        Self { value, label: None }
+    }
+}
+
+impl<S: ToString> From<(f32, S)> for Tick {
+    fn from((value, label): (f32, S)) -> Self {
+        Self {
+            value,
+            label: Some(label.to_string()),
+        }
+    }
+}
+
+/// Where the ticks of an axis are placed and how they are labeled.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(default))]
+pub struct Ticks {
+    /// The largest number of labeled ticks, or the renderer's default.
+    pub major: Option<usize>,
+    /// The largest number of unlabeled grid lines between two labeled ticks.
+    pub minor: usize,
+    /// Places the ticks at these positions, instead of choosing them from the range.
+    pub explicit: Vec<Tick>,
+    #[cfg_attr(feature = "serde", serde(serialize_with = "serialize_format"))]
+    pub format: TickFormat,
+}
+
+/// Serializes a [`TickFormat::Custom`] as [`TickFormat::Auto`], as its closure can't be.
+#[cfg(feature = "serde")]
+fn serialize_format<S: serde::Serializer>(
+    format: &TickFormat,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    match format {
+        TickFormat::Custom(_) => TickFormat::Auto.serialize(serializer),
+        format => format.serialize(serializer),
+    }
+}
+
+impl std::default::Default for Ticks {
+    fn default() -> Self {
+        Self {
+            major: None,
+            minor: 1,
+            explicit: vec![],
+            format: Default::default(),
+        }
+    }
+}
+
+impl Ticks {
+    /// Returns the label of the tick at `value`: the label of an explicit tick placed there, or
+    /// the value formatted with `format`.
+    pub fn label(&self, value: f32) -> Option<String> {
+        let explicit = self
+            .explicit
+            .iter()
+            .find(|tick| tick.value == value)
+            .and_then(|tick| tick.label.clone());
+        explicit.or_else(|| self.format.format(value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn format(format: TickFormat, value: f32) -> String {
+        format.format(value).unwrap()
+    }
+
+    #[test]
+    fn formats_numbers() {
+        assert_eq!(TickFormat::Auto.format(1.0), None);
+        assert_eq!(format(TickFormat::Fixed(2), 1.5), "1.50");
+        assert_eq!(format(TickFormat::Scientific(2), 1500.0), "1.50e3");
+        assert_eq!(format(TickFormat::Percent, 0.25), "25%");
+        assert_eq!(format(TickFormat::Percent, 0.125), "12.5%");
+    }
+
+    #[test]
+    fn formats_prefixes() {
+        assert_eq!(format(TickFormat::Si, 0.0), "0");
+        assert_eq!(format(TickFormat::Si, 1500.0), "1.5k");
+        assert_eq!(format(TickFormat::Si, 20e6), "20M");
+        assert_eq!(format(TickFormat::Si, 0.002), "2m");
+        assert_eq!(format(TickFormat::Si, -1500.0), "-1.5k");
+        assert_eq!(format(TickFormat::Bytes, 512.0), "512 B");
+        assert_eq!(
+            format(TickFormat::Bytes, 1.2 * 1024.0 * 1024.0 * 1024.0),
+            "1.2 GiB"
+        );
+    }
+
+    #[test]
+    fn formats_durations() {
+        let duration = |seconds| format(TickFormat::Duration, seconds);
+        assert_eq!(duration(0.0), "0s");
+        assert_eq!(duration(0.25), "250ms");
+        assert_eq!(duration(12.5), "12.5s");
+        assert_eq!(duration(90.0), "1m 30s");
+        assert_eq!(duration(5400.0), "1h 30m");
+        assert_eq!(duration(-5400.0), "-1h 30m");
+        assert_eq!(duration(187200.0), "2d 4h");
+    }
+
+    #[test]
+    fn durations_carry_into_the_larger_unit() {
+        let duration = |seconds| format(TickFormat::Duration, seconds);
+        assert_eq!(duration(0.99996), "1s");
+        assert_eq!(duration(59.96), "1m");
+        assert_eq!(duration(3599.9), "1h");
+        assert_eq!(duration(7199.0), "2h");
+        assert_eq!(duration(86399.0), "1d");
+    }
+
+    #[test]
+    fn explicit_labels_override_the_format() {
+        let ticks = Ticks {
+            explicit: vec![Tick::from((1.0, "one")), Tick::from(2.0)],
+            format: TickFormat::Fixed(1),
+            ..Default::default()
+        };
+        assert_eq!(ticks.label(1.0).as_deref(), Some("one"));
+        assert_eq!(ticks.label(2.0).as_deref(), Some("2.0"));
+        assert_eq!(ticks.label(3.0).as_deref(), Some("3.0"));
+    }
+
+    #[test]
+    fn custom_formats_call_their_closure() {
+        let custom = TickFormat::Custom(TickFormatter(Arc::new(|v| format!("<{v}>"))));
+        assert_eq!(format(custom, 2.0), "<2>");
+    }
+}
@@ -0,0 +1,345 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+const MONTHS: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+
+const MINUTE: i64 = 60;
+const HOUR: i64 = 60 * MINUTE;
+const DAY: i64 = 24 * HOUR;
+
+/// A point in time that can be placed on a time axis.
+pub trait Timestamp {
+    /// Returns the number of seconds since the Unix epoch.
+    fn unix_seconds(&self) -> f64;
+}
+
+impl<T: Timestamp> Timestamp for &T {
+    fn unix_seconds(&self) -> f64 {
+        (*self).unix_seconds()
+    }
+}
+
+impl Timestamp for SystemTime {
+    fn unix_seconds(&self) -> f64 {
+        match self.duration_since(UNIX_EPOCH) {
+            Ok(d) => d.as_secs_f64(),
+            Err(e) => -e.duration().as_secs_f64(),
+        }
+    }
+}
+
+/// Seconds since the Unix epoch.
+impl Timestamp for i64 {
+    fn unix_seconds(&self) -> f64 {
+        *self as f64
+    }
+}
+
+/// Seconds since the Unix epoch.
+impl Timestamp for f64 {
+    fn unix_seconds(&self) -> f64 {
+        *self
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl<Tz: chrono::TimeZone> Timestamp for chrono::DateTime<Tz> {
+    fn unix_seconds(&self) -> f64 {
+        self.timestamp() as f64 + self.timestamp_subsec_nanos() as f64 * 1e-9
+    }
+}
+
+/// Interpreted as UTC.
+#[cfg(feature = "chrono")]
+impl Timestamp for chrono::NaiveDateTime {
+    fn unix_seconds(&self) -> f64 {
+        self.and_utc().unix_seconds()
+    }
+}
+
+/// The offset of a timezone from UTC, in seconds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct UtcOffset(pub i32);
+
+impl UtcOffset {
+    pub fn hours(hours: i32) -> Self {
+        Self(hours * HOUR as i32)
+    }
+
+    pub fn minutes(minutes: i32) -> Self {
+        Self(minutes * MINUTE as i32)
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl From<chrono::FixedOffset> for UtcOffset {
+    fn from(offset: chrono::FixedOffset) -> Self {
+        Self(offset.local_minus_utc())
+    }
+}
+
+/// Calendar units that ticks of a time axis are aligned to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimeUnit {
+    Second,
+    Minute,
+    Hour,
+    Day,
+    Month,
+    Year,
+}
+
+/// The spacing of the ticks of a time axis, such as every 15 minutes or every 3 months.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimeStep {
+    pub unit: TimeUnit,
+    pub count: i64,
+}
+
+impl TimeStep {
+    const STEPS: [(TimeUnit, &'static [i64]); 6] = [
+        (TimeUnit::Second, &[1, 2, 5, 10, 15, 30]),
+        (TimeUnit::Minute, &[1, 2, 5, 10, 15, 30]),
+        (TimeUnit::Hour, &[1, 2, 3, 6, 12]),
+        (TimeUnit::Day, &[1, 2, 7, 14]),
+        (TimeUnit::Month, &[1, 2, 3, 6]),
+        (TimeUnit::Year, &[1, 2, 5, 10, 20, 50, 100, 200, 500, 1000]),
+    ];
+
+    /// Returns the smallest step that places at most `max_ticks` ticks over `span` seconds.
+    pub fn for_span(span: f64, max_ticks: usize) -> Self {
+        let max_ticks = max_ticks.max(1) as f64;
+        Self::STEPS
+            .iter()
+            .flat_map(|(unit, counts)| counts.iter().map(|&count| Self { unit: *unit, count }))
+            .find(|step| span / step.approx_seconds() <= max_ticks)
+            .unwrap_or(Self {
+                unit: TimeUnit::Year,
+                count: 1000,
+            })
+    }
+
+    /// Returns the step's length in seconds, taking months and years at their average length.
+    pub fn approx_seconds(self) -> f64 {
+        let unit = match self.unit {
+            TimeUnit::Second => 1.0,
+            TimeUnit::Minute => MINUTE as f64,
+            TimeUnit::Hour => HOUR as f64,
+            TimeUnit::Day => DAY as f64,
+            TimeUnit::Month => 30.44 * DAY as f64,
+            TimeUnit::Year => 365.25 * DAY as f64,
+        };
+        unit * self.count as f64
+    }
+
+    /// Returns the ticks between `start` and `end`, which are local seconds since the epoch.
+    /// Ticks are aligned to the calendar, so that monthly ticks fall on the first of the month
+    /// and weekly ticks on Mondays.
+    fn ticks(self, start: f64, end: f64) -> Vec<i64> {
+        let fixed = match self.unit {
+            TimeUnit::Second => Some(1),
+            TimeUnit::Minute => Some(MINUTE),
+            TimeUnit::Hour => Some(HOUR),
+            TimeUnit::Day => Some(DAY),
+            TimeUnit::Month | TimeUnit::Year => None,
+        };
+
+        if let Some(unit) = fixed {
+            let step = unit * self.count;
+            // 1970-01-05 was a Monday
+            let phase = if self.unit == TimeUnit::Day && self.count >= 7 {
+                4 * DAY
+            } else {
+                0
+            };
+            let first = ((start - phase as f64) / step as f64).ceil() as i64 * step + phase;
+            return (0..)
+                .map(|i| first + i * step)
+                .take_while(|t| *t as f64 <= end)
+                .collect();
+        }
+
+        let (year, month, _) = civil_from_days((start / DAY as f64).floor() as i64);
+        let months_per_step = match self.unit {
+            TimeUnit::Year => 12 * self.count,
+            _ => self.count,
+        };
+        // months since year 0, rounded down to a multiple of the step
+        let first = (year * 12 + month - 1).div_euclid(months_per_step) * months_per_step;
+        (0..)
+            .map(|i| {
+                let m = first + i * months_per_step;
+                days_from_civil(m.div_euclid(12), m.rem_euclid(12) + 1, 1) * DAY
+            })
+            .skip_while(|t| (*t as f64) < start)
+            .take_while(|t| *t as f64 <= end)
+            .collect()
+    }
+}
+
+/// Marks an axis as showing time. Its values are seconds since `epoch`, which keeps them precise
+/// in `f32` for spans of up to a few years.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TimeAxis {
+    /// Seconds since the Unix epoch at which the axis' values are zero.
+    pub epoch: i64,
+    /// The timezone in which ticks are placed and labeled.
+    pub utc_offset: UtcOffset,
+}
+
+impl TimeAxis {
+    fn local_seconds(&self, value: f32) -> f64 {
+        (self.epoch + self.utc_offset.0 as i64) as f64 + value as f64
+    }
+
+    fn value_at(&self, local: i64) -> f32 {
+        (local - self.epoch - self.utc_offset.0 as i64) as f32
+    }
+
+    /// Returns the step that places at most `max_ticks` ticks within `(lo, hi)`.
+    pub fn step(&self, (lo, hi): (f32, f32), max_ticks: usize) -> TimeStep {
+        TimeStep::for_span((hi - lo).abs() as f64, max_ticks)
+    }
+
+    /// Returns the ticks placed with `step` within `(lo, hi)`.
+    pub fn ticks(&self, (lo, hi): (f32, f32), step: TimeStep) -> Vec<f32> {
+        step.ticks(self.local_seconds(lo), self.local_seconds(hi))
+            .into_iter()
+            .map(|t| self.value_at(t))
+            .collect()
+    }
+
+    /// Formats a tick placed with `step`, naming the date at midnight for steps below a day.
+    pub fn format(&self, value: f32, step: TimeStep) -> String {
+        let local = self.local_seconds(value).round() as i64;
+        let (days, seconds) = (local.div_euclid(DAY), local.rem_euclid(DAY));
+        let (year, month, day) = civil_from_days(days);
+        let month_name = MONTHS[month as usize - 1];
+        let (h, m, s) = (seconds / HOUR, seconds % HOUR / MINUTE, seconds % MINUTE);
+
+        match step.unit {
+            TimeUnit::Year => format!("{year}"),
+            TimeUnit::Month => format!("{month_name} {year}"),
+            TimeUnit::Day => format!("{month_name} {day}"),
+            _ if seconds == 0 => format!("{month_name} {day}"),
+            TimeUnit::Hour | TimeUnit::Minute => format!("{h:02}:{m:02}"),
+            TimeUnit::Second => format!("{h:02}:{m:02}:{s:02}"),
+        }
+    }
+}
+
+/// Returns the number of days since 1970-01-01 of a date in the proleptic Gregorian calendar.
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146097 + day_of_era - 719468
+}
+
+/// The inverse of [`days_from_civil`], returning `(year, month, day)`.
+fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let z = days + 719468;
+    let era = z.div_euclid(146097);
+    let day_of_era = z - era * 146097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year, month, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 2024-01-01, a Monday
+    const NEW_YEAR_2024: i64 = 19723 * DAY;
+
+    fn step(unit: TimeUnit, count: i64) -> TimeStep {
+        TimeStep { unit, count }
+    }
+
+    #[test]
+    fn civil_dates_convert_to_days_and_back() {
+        assert_eq!(days_from_civil(1970, 1, 1), 0);
+        assert_eq!(days_from_civil(1969, 12, 31), -1);
+        assert_eq!(days_from_civil(2000, 3, 1), 11017);
+        assert_eq!(days_from_civil(2024, 2, 29), 19782);
+        assert_eq!(civil_from_days(19782), (2024, 2, 29));
+        for days in (-800_000..800_000).step_by(997) {
+            let (year, month, day) = civil_from_days(days);
+            assert_eq!(days_from_civil(year, month, day), days);
+        }
+    }
+
+    #[test]
+    fn steps_fit_the_span() {
+        assert_eq!(
+            TimeStep::for_span(HOUR as f64, 5),
+            step(TimeUnit::Minute, 15)
+        );
+        assert_eq!(
+            TimeStep::for_span(10.0 * DAY as f64, 4),
+            step(TimeUnit::Day, 7)
+        );
+        assert_eq!(TimeStep::for_span(30.0, 100), step(TimeUnit::Second, 1));
+        assert_eq!(TimeStep::for_span(1e15, 5), step(TimeUnit::Year, 1000));
+    }
+
+    #[test]
+    fn monthly_ticks_fall_on_the_first() {
+        let axis = TimeAxis {
+            epoch: NEW_YEAR_2024 + 14 * DAY,
+            utc_offset: UtcOffset::default(),
+        };
+        let month = step(TimeUnit::Month, 1);
+        let labels = axis
+            .ticks((0.0, 100.0 * DAY as f32), month)
+            .into_iter()
+            .map(|t| axis.format(t, month))
+            .collect::<Vec<_>>();
+        assert_eq!(labels, ["Feb 2024", "Mar 2024", "Apr 2024"]);
+    }
+
+    #[test]
+    fn weekly_ticks_fall_on_mondays() {
+        let axis = TimeAxis {
+            epoch: NEW_YEAR_2024,
+            utc_offset: UtcOffset::default(),
+        };
+        let ticks = axis.ticks(
+            (-3.0 * DAY as f32, 20.0 * DAY as f32),
+            step(TimeUnit::Day, 7),
+        );
+        assert_eq!(ticks, [0.0, 7.0 * DAY as f32, 14.0 * DAY as f32]);
+    }
+
+    #[test]
+    fn ticks_and_labels_use_the_offset() {
+        let axis = TimeAxis {
+            epoch: NEW_YEAR_2024,
+            utc_offset: UtcOffset::minutes(90),
+        };
+        let hour = step(TimeUnit::Hour, 1);
+        assert_eq!(
+            axis.ticks((0.0, 3.0 * HOUR as f32), hour),
+            [1800.0, 5400.0, 9000.0]
+        );
+        assert_eq!(axis.format(1800.0, hour), "02:00");
+        // local midnight names the date
+        assert_eq!(axis.format(22.5 * HOUR as f32, hour), "Jan 2");
+        assert_eq!(axis.format(61.0, step(TimeUnit::Second, 1)), "01:31:01");
+        assert_eq!(axis.format(0.0, step(TimeUnit::Year, 1)), "2024");
+    }
+}
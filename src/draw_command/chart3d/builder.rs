@@ -0,0 +1,224 @@
+use rgb::RGBA;
+
+use crate::{
+    colors::{colormap::Colormap, Palette},
+    draw_command::chart::color_scale::Colorbar,
+    point_data::IntoSamples,
+    stats::linspace,
+};
+
+use super::{Camera, Chart3D, Chart3DSeries, Chart3DSeriesType, Chart3DSurfaceConfig};
+
+pub struct Chart3DSeriesBuilder {
+    pub s: Chart3DSeries,
+    pub auto_color: bool,
+}
+
+impl std::default::Default for Chart3DSeriesBuilder {
+    fn default() -> Self {
+        Self {
+            s: Chart3DSeries {
+                name: "".to_string(),
+                data: Vec::new(),
+                chart_series_type: Default::default(),
+                style: Default::default(),
+            },
+            auto_color: true,
+        }
+    }
+}
+
+impl Chart3DSeriesBuilder {
+    pub fn name(mut self, name: impl ToString) -> Self {
+        self.s.name = name.to_string();
+        self
+    }
+
+    /// Sets the `(x, y, z)` points of line and scatter series.
+    pub fn data(mut self, data: impl IntoIterator<Item = (f32, f32, f32)>) -> Self {
+        self.s.data = data.into_iter().collect();
+        self
+    }
+
+    pub fn color(mut self, color: impl Into<RGBA<f32>>) -> Self {
+        self.s.style.color = color.into();
+        self.auto_color = false;
+        self
+    }
+
+    pub fn line(mut self) -> Self {
+        self.s.chart_series_type = Chart3DSeriesType::Line;
+        self
+    }
+
+    pub fn scatter(mut self) -> Self {
+        self.s.chart_series_type = Chart3DSeriesType::Scatter;
+        self
+    }
+
+    /// Draws a surface from a grid of heights spanning `x_extent` and `y_extent`. Each item of
+    /// `rows` is a row of grid nodes along x, starting from the lowest y.
+    pub fn surface(
+        mut self,
+        rows: impl IntoIterator<Item = impl IntoSamples>,
+        x_extent: (f32, f32),
+        y_extent: (f32, f32),
+    ) -> Self {
+        self.s.chart_series_type = Chart3DSeriesType::Surface(Chart3DSurfaceConfig {
+            values: rows.into_iter().map(IntoSamples::into_samples).collect(),
+            x_extent,
+            y_extent,
+            ..Default::default()
+        });
+        self
+    }
+
+    /// Draws the surface `z = f(x, y)`, sampled on a grid of `cols` by `rows` nodes spanning
+    /// `x_extent` and `y_extent`.
+    pub fn surface_fn(
+        self,
+        f: impl Fn(f32, f32) -> f32,
+        x_extent: (f32, f32),
+        y_extent: (f32, f32),
+        (cols, rows): (usize, usize),
+    ) -> Self {
+        let (x0, x1) = x_extent;
+        let (y0, y1) = y_extent;
+        let values = linspace(y0, y1, rows)
+            .map(|y| linspace(x0, x1, cols).map(|x| f(x, y)).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        self.surface(values, x_extent, y_extent)
+    }
+
+    /// Sets the colormap of surface series.
+    pub fn colormap(mut self, colormap: Colormap) -> Self {
+        if let Chart3DSeriesType::Surface(s) = &mut self.s.chart_series_type {
+            s.color_scale.colormap = colormap;
+        }
+        self
+    }
+
+    pub fn colorbar(mut self, label: impl ToString) -> Self {
+        if let Chart3DSeriesType::Surface(s) = &mut self.s.chart_series_type {
+            s.color_scale.colorbar = Some(Colorbar {
+                label: label.to_string(),
+            });
+        }
+        self
+    }
+
+    pub fn no_colorbar(mut self) -> Self {
+        if let Chart3DSeriesType::Surface(s) = &mut self.s.chart_series_type {
+            s.color_scale.colorbar = None;
+        }
+        self
+    }
+
+    pub fn build(self) -> Chart3DSeries {
+        self.s
+    }
+
+    pub fn build_with_color(self, color: impl Into<RGBA<f32>>) -> Chart3DSeries {
+        let mut s = self.s;
+        if self.auto_color {
+            s.style.color = color.into();
+        }
+        s
+    }
+}
+
+pub struct Chart3DBuilder {
+    c: Chart3D,
+    p: Palette,
+    series: Vec<Chart3DSeriesBuilder>,
+}
+
+impl std::default::Default for Chart3DBuilder {
+    fn default() -> Self {
+        Self {
+            c: Chart3D {
+                series: vec![],
+                x_label: "".to_string(),
+                y_label: "".to_string(),
+                z_label: "".to_string(),
+                title: "".to_string(),
+                camera: Default::default(),
+            },
+            p: Default::default(),
+            series: vec![],
+        }
+    }
+}
+
+impl Chart3DBuilder {
+    pub fn add_series(mut self, series: Chart3DSeriesBuilder) -> Self {
+        self.series.push(series);
+        self
+    }
+
+    pub fn add_series_with(
+        self,
+        f: impl FnOnce(Chart3DSeriesBuilder) -> Chart3DSeriesBuilder,
+    ) -> Self {
+        let csb = Chart3DSeriesBuilder::default();
+        self.add_series(f(csb))
+    }
+
+    pub fn x_label(mut self, label: impl ToString) -> Self {
+        self.c.x_label = label.to_string();
+        self
+    }
+
+    pub fn y_label(mut self, label: impl ToString) -> Self {
+        self.c.y_label = label.to_string();
+        self
+    }
+
+    pub fn z_label(mut self, label: impl ToString) -> Self {
+        self.c.z_label = label.to_string();
+        self
+    }
+
+    pub fn title(mut self, title: impl ToString) -> Self {
+        self.c.title = title.to_string();
+        self
+    }
+
+    pub fn camera(mut self, camera: Camera) -> Self {
+        self.c.camera = camera;
+        self
+    }
+
+    /// Sets the rotation of the view around the vertical axis, in radians.
+    pub fn yaw(mut self, yaw: f32) -> Self {
+        self.c.camera.yaw = yaw;
+        self
+    }
+
+    /// Sets the elevation of the view above the x-y plane, in radians.
+    pub fn pitch(mut self, pitch: f32) -> Self {
+        self.c.camera.pitch = pitch;
+        self
+    }
+
+    pub fn scale(mut self, scale: f32) -> Self {
+        self.c.camera.scale = scale;
+        self
+    }
+
+    pub fn build(self) -> Chart3D {
+        let mut chart = self.c;
+
+        let mut colors = self.p.iter();
+        for s in self.series {
+            let s = if let Some(c) = colors.next() {
+                s.build_with_color(c)
+            } else {
+                s.build()
+            };
+            chart.series.push(s);
+        }
+
+        chart
+    }
+}
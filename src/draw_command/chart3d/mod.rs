@@ -0,0 +1,154 @@
+#[cfg(feature = "builder")]
+pub mod builder;
+
+use crate::{
+    contour::Grid,
+    data_bound::{DataBound, DataBound2D},
+};
+
+use super::chart::{color_scale::ColorScale, series_style::SeriesStyle};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// A surface `z = f(x, y)` sampled on a regular grid. `values[row][col]` is the height at the grid
+/// node `col` along x and `row` along y, with the first row and column at the start of the
+/// extents.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Chart3DSurfaceConfig {
+    pub values: Vec<Vec<f32>>,
+    pub x_extent: (f32, f32),
+    pub y_extent: (f32, f32),
+    /// Colors the surface by height.
+    pub color_scale: ColorScale,
+}
+
+impl std::default::Default for Chart3DSurfaceConfig {
+    fn default() -> Self {
+        Self {
+            values: Vec::new(),
+            x_extent: (0.0, 1.0),
+            y_extent: (0.0, 1.0),
+            color_scale: Default::default(),
+        }
+    }
+}
+
+impl Chart3DSurfaceConfig {
+    pub fn grid(&self) -> Grid<'_> {
+        Grid {
+            values: &self.values,
+            x_extent: self.x_extent,
+            y_extent: self.y_extent,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Chart3DSeriesType {
+    #[default]
+    Line,
+    Scatter,
+    Surface(Chart3DSurfaceConfig),
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Chart3DSeries {
+    pub name: String,
+    /// The `(x, y, z)` points of line and scatter series.
+    pub data: Vec<(f32, f32, f32)>,
+    pub chart_series_type: Chart3DSeriesType,
+    pub style: SeriesStyle,
+}
+
+impl Chart3DSeries {
+    /// Returns the x, y and z bounds of the series.
+    pub fn bounds(&self) -> Option<(DataBound, DataBound, DataBound)> {
+        match &self.chart_series_type {
+            Chart3DSeriesType::Surface(s) => {
+                let (lo, hi) = s.grid().range()?;
+                Some((
+                    DataBound::from(s.x_extent.0).extend_to_include(s.x_extent.1),
+                    DataBound::from(s.y_extent.0).extend_to_include(s.y_extent.1),
+                    DataBound::from((lo, hi)),
+                ))
+            }
+            _ => self
+                .data
+                .iter()
+                .filter(|(x, y, z)| x.is_finite() && y.is_finite() && z.is_finite())
+                .map(|(x, y, z)| (DataBound2D::from((*x, *y)), DataBound::from(*z)))
+                .reduce(|(xy, z), (other_xy, other_z)| {
+                    (
+                        xy.extend_to_include_bound2d(other_xy),
+                        z.extend_to_include(other_z),
+                    )
+                })
+                .map(|(xy, z)| (xy.x, xy.y, z)),
+        }
+    }
+
+    /// Returns the color scale of surface series along with the range of heights it maps onto
+    /// the colormap.
+    pub fn color_range(&self) -> Option<(&ColorScale, (f32, f32))> {
+        match &self.chart_series_type {
+            Chart3DSeriesType::Surface(s) => {
+                let range = s.color_scale.resolve(s.values.iter().flatten().copied())?;
+                Some((&s.color_scale, range))
+            }
+            _ => None,
+        }
+    }
+}
+
+/// The view of a 3-D chart. Angles are in radians.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Camera {
+    /// Rotation around the vertical axis.
+    pub yaw: f32,
+    /// Elevation of the view above the x-y plane.
+    pub pitch: f32,
+    /// Zoom factor, where 1 fits the axes' box to the drawing area.
+    pub scale: f32,
+}
+
+impl std::default::Default for Camera {
+    fn default() -> Self {
+        Self {
+            yaw: 0.5,
+            pitch: 0.3,
+            scale: 0.8,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Chart3D {
+    pub series: Vec<Chart3DSeries>,
+    pub x_label: String,
+    pub y_label: String,
+    pub z_label: String,
+    pub title: String,
+    pub camera: Camera,
+}
+
+impl Chart3D {
+    /// Returns the x, y and z bounds of the chart's data.
+    pub fn bounds(&self) -> Option<(DataBound, DataBound, DataBound)> {
+        self.series
+            .iter()
+            .filter_map(Chart3DSeries::bounds)
+            .reduce(|(x, y, z), (ox, oy, oz)| {
+                (
+                    x.extend_to_include(ox),
+                    y.extend_to_include(oy),
+                    z.extend_to_include(oz),
+                )
+            })
+    }
+}
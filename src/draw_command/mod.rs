@@ -1,11 +1,15 @@
 pub mod chart;
+pub mod chart3d;
 pub mod image;
 pub mod layout;
+pub mod pie;
 
 use self::image::Image;
 use chart::chart::Chart;
+use chart3d::Chart3D;
 use derive_more::From;
 use layout::Layout;
+use pie::Pie;
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -15,8 +19,10 @@ use serde::{Deserialize, Serialize};
 pub enum DrawComand {
     Blank,
     Chart(Box<Chart>),
+    Chart3D(Box<Chart3D>),
     Image(Box<Image>),
     Layout(Box<Layout>),
+    Pie(Box<Pie>),
 }
 
 impl From<Chart> for DrawComand {
@@ -25,6 +31,12 @@ impl From<Chart> for DrawComand {
     }
 }
 
+impl From<Chart3D> for DrawComand {
+    fn from(value: Chart3D) -> Self {
+        Self::Chart3D(Box::new(value))
+    }
+}
+
 impl From<Image> for DrawComand {
     fn from(value: Image) -> Self {
         Self::Image(Box::new(value))
@@ -37,6 +49,12 @@ impl From<Layout> for DrawComand {
     }
 }
 
+impl From<Pie> for DrawComand {
+    fn from(value: Pie) -> Self {
+        Self::Pie(Box::new(value))
+    }
+}
+
 #[cfg(feature = "plotters")]
 pub mod with_plotters {
     use super::DrawComand;
@@ -60,8 +78,10 @@ pub mod with_plotters {
 mod conversions {
     use super::{
         chart::{chart_builder::ChartBuilder, IntoChart},
+        chart3d::{builder::Chart3DBuilder, Chart3D},
         image::{builder::ImageBuilder, Image},
         layout::layout_builder::LayoutBuilder,
+        pie::{builder::PieBuilder, Pie},
         DrawComand,
     };
 
@@ -81,6 +101,18 @@ mod conversions {
         }
     }
 
+    impl IntoDrawCommand for Chart3D {
+        fn into_draw_command(self) -> DrawComand {
+            DrawComand::from(self)
+        }
+    }
+
+    impl IntoDrawCommand for Chart3DBuilder {
+        fn into_draw_command(self) -> DrawComand {
+            DrawComand::from(self.build())
+        }
+    }
+
     impl IntoDrawCommand for LayoutBuilder {
         fn into_draw_command(self) -> DrawComand {
             DrawComand::from(self.build())
@@ -93,6 +125,18 @@ mod conversions {
         }
     }
 
+    impl IntoDrawCommand for Pie {
+        fn into_draw_command(self) -> DrawComand {
+            DrawComand::from(self)
+        }
+    }
+
+    impl IntoDrawCommand for PieBuilder {
+        fn into_draw_command(self) -> DrawComand {
+            DrawComand::from(self.build())
+        }
+    }
+
     pub fn plot(cmd: impl IntoDrawCommand) -> DrawComand {
         cmd.into_draw_command()
     }
@@ -116,6 +160,18 @@ mod conversions {
         plot(b.build())
     }
 
+    pub fn plot_chart3d(f: impl FnOnce(Chart3DBuilder) -> Chart3DBuilder) -> DrawComand {
+        let b = Chart3DBuilder::default();
+        let b = f(b);
+        plot(b)
+    }
+
+    pub fn plot_pie(f: impl FnOnce(PieBuilder) -> PieBuilder) -> DrawComand {
+        let b = PieBuilder::default();
+        let b = f(b);
+        plot(b)
+    }
+
     pub fn plot_layout(f: impl FnOnce(LayoutBuilder) -> LayoutBuilder) -> DrawComand {
         let b = LayoutBuilder::default();
         let b = f(b);
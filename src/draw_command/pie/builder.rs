@@ -0,0 +1,112 @@
+use rgb::RGBA;
+
+use crate::colors::Palette;
+
+use super::{Pie, PieSlice};
+
+pub struct PieBuilder {
+    p: Pie,
+    palette: Palette,
+    /// Whether each slice is colored from the palette when built.
+    auto_color: Vec<bool>,
+}
+
+impl std::default::Default for PieBuilder {
+    fn default() -> Self {
+        Self {
+            p: Pie {
+                title: "".to_string(),
+                slices: vec![],
+                donut_hole: 0.0,
+                start_angle: -90.0,
+                percentages: true,
+                labels: false,
+                legend: true,
+            },
+            palette: Default::default(),
+            auto_color: vec![],
+        }
+    }
+}
+
+impl PieBuilder {
+    pub fn slice(mut self, label: impl ToString, value: f32) -> Self {
+        self.p.slices.push(PieSlice {
+            label: label.to_string(),
+            value,
+            color: Default::default(),
+        });
+        self.auto_color.push(true);
+        self
+    }
+
+    pub fn slice_with_color(
+        mut self,
+        label: impl ToString,
+        value: f32,
+        color: impl Into<RGBA<f32>>,
+    ) -> Self {
+        self.p.slices.push(PieSlice {
+            label: label.to_string(),
+            value,
+            color: color.into(),
+        });
+        self.auto_color.push(false);
+        self
+    }
+
+    pub fn slices(self, slices: impl IntoIterator<Item = (impl ToString, f32)>) -> Self {
+        slices
+            .into_iter()
+            .fold(self, |acc, (label, value)| acc.slice(label, value))
+    }
+
+    pub fn title(mut self, title: impl ToString) -> Self {
+        self.p.title = title.to_string();
+        self
+    }
+
+    /// Draws a donut chart, with a hole of the given fraction of the radius.
+    pub fn donut(mut self, hole: f32) -> Self {
+        self.p.donut_hole = hole;
+        self
+    }
+
+    pub fn start_angle(mut self, degrees: f32) -> Self {
+        self.p.start_angle = degrees;
+        self
+    }
+
+    pub fn no_percentages(mut self) -> Self {
+        self.p.percentages = false;
+        self
+    }
+
+    pub fn labels(mut self) -> Self {
+        self.p.labels = true;
+        self
+    }
+
+    pub fn no_legend(mut self) -> Self {
+        self.p.legend = false;
+        self
+    }
+
+    pub fn build(self) -> Pie {
+        let mut pie = self.p;
+
+        let colors = self.palette.iter().collect::<Vec<_>>();
+        let auto_colored = pie
+            .slices
+            .iter_mut()
+            .zip(self.auto_color)
+            .filter_map(|(slice, auto)| auto.then_some(slice));
+        for (i, slice) in auto_colored.enumerate() {
+            if let Some(c) = colors.get(i % colors.len().max(1)) {
+                slice.color = *c;
+            }
+        }
+
+        pie
+    }
+}
@@ -0,0 +1,40 @@
+#[cfg(feature = "builder")]
+pub mod builder;
+
+use rgb::RGBA;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PieSlice {
+    pub label: String,
+    pub value: f32,
+    pub color: RGBA<f32>,
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Pie {
+    pub title: String,
+    pub slices: Vec<PieSlice>,
+    /// Radius of the hole in the middle as a fraction of the pie's radius. Zero draws a full pie.
+    pub donut_hole: f32,
+    /// Angle in degrees at which the first slice starts, clockwise from the positive x axis.
+    pub start_angle: f32,
+    /// Draw each slice's share of the total inside of it.
+    pub percentages: bool,
+    /// Draw each slice's label next to it.
+    pub labels: bool,
+    pub legend: bool,
+}
+
+impl Pie {
+    /// Returns the slices that can be drawn: those with a finite, positive value.
+    pub fn visible_slices(&self) -> impl Iterator<Item = &PieSlice> {
+        self.slices
+            .iter()
+            .filter(|slice| slice.value.is_finite() && slice.value > 0.0)
+    }
+}
@@ -1,6 +1,9 @@
+pub mod binning;
 pub mod cmd_processor;
 pub mod colors;
+pub mod contour;
 pub mod data_bound;
 pub mod draw_command;
 pub mod map_reduce;
 pub mod point_data;
+pub mod stats;
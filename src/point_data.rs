@@ -1,3 +1,7 @@
+use std::collections::{BTreeMap, HashMap};
+
+use itertools::Itertools;
+
 pub trait IntoData {
     fn into_data(self) -> Vec<(f32, f32)>;
 }
@@ -157,3 +161,95 @@ impl_item_for_primitive!(i64);
 impl_item_for_primitive!(u8);
 impl_item_for_primitive!(u32);
 impl_item_for_primitive!(u64);
+
+/// Conversion into a flat list of samples, used by series that are computed from raw
+/// observations rather than from `(x, y)` points.
+pub trait IntoSamples {
+    fn into_samples(self) -> Vec<f32>;
+}
+
+impl<I: IntoIterator> IntoSamples for I
+where
+    I::Item: IntoSample,
+{
+    fn into_samples(self) -> Vec<f32> {
+        self.into_iter().map(IntoSample::into_sample).collect()
+    }
+}
+
+pub trait IntoSample {
+    fn into_sample(self) -> f32;
+}
+
+macro_rules! impl_sample_for_primitive {
+    ($ty:ty) => {
+        impl IntoSample for $ty {
+            fn into_sample(self) -> f32 {
+                self as _
+            }
+        }
+
+        impl IntoSample for &$ty {
+            fn into_sample(self) -> f32 {
+                *self as _
+            }
+        }
+    };
+}
+
+impl_sample_for_primitive!(f32);
+impl_sample_for_primitive!(f64);
+impl_sample_for_primitive!(i8);
+impl_sample_for_primitive!(i32);
+impl_sample_for_primitive!(i64);
+impl_sample_for_primitive!(u8);
+impl_sample_for_primitive!(u32);
+impl_sample_for_primitive!(u64);
+
+/// Conversion into values keyed by category names, in the order the categories appear along the
+/// axis.
+pub trait IntoCategoricalData {
+    fn into_categorical_data(self) -> Vec<(String, f32)>;
+}
+
+impl<K: ToString, V: IntoSample> IntoCategoricalData for Vec<(K, V)> {
+    fn into_categorical_data(self) -> Vec<(String, f32)> {
+        self.into_iter()
+            .map(|(k, v)| (k.to_string(), v.into_sample()))
+            .collect()
+    }
+}
+
+impl<K: ToString, V: IntoSample, const N: usize> IntoCategoricalData for [(K, V); N] {
+    fn into_categorical_data(self) -> Vec<(String, f32)> {
+        self.into_iter()
+            .map(|(k, v)| (k.to_string(), v.into_sample()))
+            .collect()
+    }
+}
+
+impl<K: ToString, V: IntoSample + Copy> IntoCategoricalData for &[(K, V)] {
+    fn into_categorical_data(self) -> Vec<(String, f32)> {
+        self.iter()
+            .map(|(k, v)| (k.to_string(), v.into_sample()))
+            .collect()
+    }
+}
+
+/// Categories are sorted by key, since the map's iteration order is arbitrary.
+impl<K: ToString + Ord, V: IntoSample, S> IntoCategoricalData for HashMap<K, V, S> {
+    fn into_categorical_data(self) -> Vec<(String, f32)> {
+        self.into_iter()
+            .sorted_by(|a, b| a.0.cmp(&b.0))
+            .map(|(k, v)| (k.to_string(), v.into_sample()))
+            .collect()
+    }
+}
+
+impl<K: ToString, V: IntoSample> IntoCategoricalData for BTreeMap<K, V> {
+    fn into_categorical_data(self) -> Vec<(String, f32)> {
+        self.into_iter()
+            .map(|(k, v)| (k.to_string(), v.into_sample()))
+            .collect()
+    }
+}
@@ -0,0 +1,308 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Returns the finite samples sorted in ascending order.
+pub fn sorted_finite(samples: &[f32]) -> Vec<f32> {
+    let mut sorted = samples
+        .iter()
+        .copied()
+        .filter(|x| x.is_finite())
+        .collect::<Vec<_>>();
+    sorted.sort_by(f32::total_cmp);
+    sorted
+}
+
+/// Returns the `q`-th quantile of already sorted samples, linearly interpolating between the
+/// closest ranks.
+pub fn quantile(sorted: &[f32], q: f32) -> Option<f32> {
+    let last = sorted.len().checked_sub(1)?;
+    let pos = q.clamp(0.0, 1.0) * last as f32;
+    let lo = pos.floor() as usize;
+    let hi = pos.ceil() as usize;
+    let t = pos - lo as f32;
+    Some(sorted[lo] + (sorted[hi] - sorted[lo]) * t)
+}
+
+pub fn mean(samples: &[f32]) -> Option<f32> {
+    if samples.is_empty() {
+        return None;
+    }
+    Some(samples.iter().sum::<f32>() / samples.len() as f32)
+}
+
+/// Sample standard deviation.
+pub fn std_dev(samples: &[f32]) -> Option<f32> {
+    let mean = mean(samples)?;
+    if samples.len() < 2 {
+        return Some(0.0);
+    }
+    let var = samples.iter().map(|x| (x - mean).powi(2)).sum::<f32>() / (samples.len() - 1) as f32;
+    Some(var.sqrt())
+}
+
+/// The statistics drawn by a box plot. Whiskers extend to the furthest samples that lie within
+/// 1.5 IQR of the box, and every sample outside of them is an outlier.
+#[derive(Debug, Clone)]
+pub struct BoxStats {
+    pub q1: f32,
+    pub median: f32,
+    pub q3: f32,
+    pub whisker_lo: f32,
+    pub whisker_hi: f32,
+    pub outliers: Vec<f32>,
+}
+
+impl BoxStats {
+    pub fn new(samples: &[f32]) -> Option<Self> {
+        let sorted = sorted_finite(samples);
+        let q1 = quantile(&sorted, 0.25)?;
+        let median = quantile(&sorted, 0.5)?;
+        let q3 = quantile(&sorted, 0.75)?;
+
+        let iqr = q3 - q1;
+        let fence_lo = q1 - 1.5 * iqr;
+        let fence_hi = q3 + 1.5 * iqr;
+        let inside = || {
+            sorted
+                .iter()
+                .copied()
+                .filter(|x| (fence_lo..=fence_hi).contains(x))
+        };
+
+        Some(Self {
+            q1,
+            median,
+            q3,
+            whisker_lo: inside().next().unwrap_or(q1),
+            whisker_hi: inside().next_back().unwrap_or(q3),
+            outliers: sorted
+                .iter()
+                .copied()
+                .filter(|x| !(fence_lo..=fence_hi).contains(x))
+                .collect(),
+        })
+    }
+
+    /// The range covered by the whiskers, and optionally the outliers.
+    pub fn extent(&self, with_outliers: bool) -> (f32, f32) {
+        let (lo, hi) = (self.whisker_lo, self.whisker_hi);
+        if with_outliers {
+            self.outliers
+                .iter()
+                .fold((lo, hi), |(lo, hi), x| (lo.min(*x), hi.max(*x)))
+        } else {
+            (lo, hi)
+        }
+    }
+}
+
+/// Scott's rule of thumb for the bandwidth of a Gaussian kernel.
+pub fn scott_bandwidth(samples: &[f32]) -> Option<f32> {
+    let sigma = std_dev(samples)?;
+    Some(1.06 * sigma * (samples.len() as f32).powf(-0.2))
+}
+
+/// Silverman's rule of thumb, which is more robust than Scott's to skewed and multimodal
+/// samples.
+pub fn silverman_bandwidth(samples: &[f32]) -> Option<f32> {
+    let sigma = std_dev(samples)?;
+    let sorted = sorted_finite(samples);
+    let iqr = quantile(&sorted, 0.75)? - quantile(&sorted, 0.25)?;
+    let spread = if iqr > 0.0 {
+        sigma.min(iqr / 1.34)
+    } else {
+        sigma
+    };
+    Some(0.9 * spread * (samples.len() as f32).powf(-0.2))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Kernel {
+    #[default]
+    Gaussian,
+    Epanechnikov,
+}
+
+impl Kernel {
+    /// Evaluates the kernel at `u`, the distance to a sample in units of bandwidth.
+    pub fn evaluate(self, u: f32) -> f32 {
+        match self {
+            Self::Gaussian => (-0.5 * u * u).exp() / (2.0 * std::f32::consts::PI).sqrt(),
+            Self::Epanechnikov if u.abs() <= 1.0 => 0.75 * (1.0 - u * u),
+            Self::Epanechnikov => 0.0,
+        }
+    }
+
+    /// Distance from a sample, in units of bandwidth, beyond which the kernel is negligible.
+    pub fn support(self) -> f32 {
+        match self {
+            Self::Gaussian => 3.0,
+            Self::Epanechnikov => 1.0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Bandwidth {
+    #[default]
+    Scott,
+    Silverman,
+    Manual(f32),
+}
+
+impl From<f32> for Bandwidth {
+    fn from(value: f32) -> Self {
+        Self::Manual(value)
+    }
+}
+
+impl Bandwidth {
+    /// Returns the bandwidth for the given samples, or `None` if they have no spread.
+    pub fn resolve(self, samples: &[f32]) -> Option<f32> {
+        match self {
+            Self::Scott => scott_bandwidth(samples),
+            Self::Silverman => silverman_bandwidth(samples),
+            Self::Manual(bw) => Some(bw),
+        }
+        .filter(|bw| *bw > 0.0)
+    }
+}
+
+/// Evaluates a kernel density estimate of `samples` at `x`.
+pub fn kde(samples: &[f32], kernel: Kernel, bandwidth: f32, x: f32) -> f32 {
+    samples
+        .iter()
+        .map(|s| kernel.evaluate((x - s) / bandwidth))
+        .sum::<f32>()
+        / (bandwidth * samples.len() as f32)
+}
+
+/// Evaluates a Gaussian kernel density estimate of `samples` at `x`.
+pub fn gaussian_kde(samples: &[f32], bandwidth: f32, x: f32) -> f32 {
+    kde(samples, Kernel::Gaussian, bandwidth, x)
+}
+
+/// Returns `n` evenly spaced values from `start` to `end`, inclusive.
+pub fn linspace(start: f32, end: f32, n: usize) -> impl Iterator<Item = f32> {
+    let step = if n > 1 {
+        (end - start) / (n - 1) as f32
+    } else {
+        0.0
+    };
+    (0..n).map(move |i| start + step * i as f32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn close(a: f32, b: f32) -> bool {
+        (a - b).abs() < 1e-4
+    }
+
+    #[test]
+    fn sorted_finite_drops_missing_samples() {
+        let samples = [3.0, f32::NAN, 1.0, f32::INFINITY, 2.0];
+        assert_eq!(sorted_finite(&samples), vec![1.0, 2.0, 3.0]);
+    }
+
+    #[test]
+    fn quantile_interpolates_between_ranks() {
+        let sorted = [1.0, 2.0, 3.0, 4.0];
+        assert_eq!(quantile(&sorted, 0.0), Some(1.0));
+        assert_eq!(quantile(&sorted, 0.5), Some(2.5));
+        assert_eq!(quantile(&sorted, 0.25), Some(1.75));
+        assert_eq!(quantile(&sorted, 1.0), Some(4.0));
+        assert_eq!(quantile(&sorted, 2.0), Some(4.0));
+        assert_eq!(quantile(&[], 0.5), None);
+    }
+
+    #[test]
+    fn mean_and_sample_standard_deviation() {
+        let samples = [1.0, 2.0, 3.0, 4.0];
+        assert_eq!(mean(&samples), Some(2.5));
+        assert!(close(std_dev(&samples).unwrap(), 1.290_994));
+        assert_eq!(std_dev(&[5.0]), Some(0.0));
+        assert_eq!(mean(&[]), None);
+        assert_eq!(std_dev(&[]), None);
+    }
+
+    #[test]
+    fn box_stats_separate_outliers() {
+        let samples = [5.0, 1.0, 2.0, 3.0, 4.0, 100.0, 6.0, 7.0, 8.0, 9.0];
+        let stats = BoxStats::new(&samples).unwrap();
+        assert_eq!((stats.q1, stats.median, stats.q3), (3.25, 5.5, 7.75));
+        assert_eq!((stats.whisker_lo, stats.whisker_hi), (1.0, 9.0));
+        assert_eq!(stats.outliers, vec![100.0]);
+        assert_eq!(stats.extent(false), (1.0, 9.0));
+        assert_eq!(stats.extent(true), (1.0, 100.0));
+        assert!(BoxStats::new(&[f32::NAN]).is_none());
+    }
+
+    #[test]
+    fn scott_bandwidth_scales_with_spread() {
+        assert!(close(
+            scott_bandwidth(&[1.0, 2.0, 3.0, 4.0]).unwrap(),
+            1.037_092
+        ));
+        assert_eq!(scott_bandwidth(&[2.0, 2.0]), Some(0.0));
+    }
+
+    #[test]
+    fn linspace_includes_both_ends() {
+        assert_eq!(
+            linspace(0.0, 1.0, 5).collect::<Vec<_>>(),
+            vec![0.0, 0.25, 0.5, 0.75, 1.0]
+        );
+        assert_eq!(linspace(3.0, 7.0, 1).collect::<Vec<_>>(), vec![3.0]);
+        assert_eq!(linspace(3.0, 7.0, 0).count(), 0);
+    }
+
+    #[test]
+    fn kernels_have_known_values() {
+        assert!(close(Kernel::Gaussian.evaluate(0.0), 0.398_942));
+        assert!(close(Kernel::Gaussian.evaluate(1.0), 0.241_971));
+        assert_eq!(Kernel::Epanechnikov.evaluate(0.0), 0.75);
+        assert_eq!(Kernel::Epanechnikov.evaluate(-0.5), 0.5625);
+        assert_eq!(Kernel::Epanechnikov.evaluate(1.5), 0.0);
+    }
+
+    #[test]
+    fn kernels_integrate_to_one_within_their_support() {
+        for kernel in [Kernel::Gaussian, Kernel::Epanechnikov] {
+            let n = 2001;
+            let support = kernel.support();
+            let step = 2.0 * support / (n - 1) as f32;
+            let area = linspace(-support, support, n)
+                .map(|u| kernel.evaluate(u) * step)
+                .sum::<f32>();
+            assert!((area - 1.0).abs() < 0.01, "{kernel:?}: {area}");
+        }
+    }
+
+    #[test]
+    fn silverman_bandwidth_uses_the_smaller_spread() {
+        let samples = [1.0, 2.0, 3.0, 4.0];
+        // the IQR of 1.5 divided by 1.34 is smaller than the standard deviation
+        assert!(close(silverman_bandwidth(&samples).unwrap(), 0.763_513));
+    }
+
+    #[test]
+    fn bandwidth_resolves_to_none_without_spread() {
+        assert_eq!(Bandwidth::Scott.resolve(&[2.0, 2.0, 2.0]), None);
+        assert_eq!(Bandwidth::Silverman.resolve(&[2.0]), None);
+        assert_eq!(Bandwidth::from(0.5).resolve(&[2.0]), Some(0.5));
+        assert_eq!(Bandwidth::Manual(0.0).resolve(&[1.0, 2.0]), None);
+    }
+
+    #[test]
+    fn kde_averages_the_scaled_kernels() {
+        assert!(close(gaussian_kde(&[0.0], 2.0, 0.0), 0.199_471));
+        let samples = [0.0, 1.0];
+        let density = kde(&samples, Kernel::Epanechnikov, 1.0, 0.5);
+        assert_eq!(density, 0.5625);
+        assert_eq!(kde(&samples, Kernel::Epanechnikov, 1.0, 3.0), 0.0);
+    }
+}